@@ -9,11 +9,11 @@
 // except according to those terms.
 
 use std::arch::aarch64::{self, float32x2_t, float32x4_t, int32x2_t, int32x4_t};
-use std::arch::aarch64::{uint32x2_t, uint32x4_t};
+use std::arch::aarch64::{uint32x2_t, uint32x4_t, uint8x16_t};
 use std::f32;
 use std::fmt::{self, Debug, Formatter};
 use std::mem;
-use std::ops::{Add, BitAnd, BitOr, Div, Index, IndexMut, Mul, Not, Shr, Sub};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Not, Shl, Shr, Sub};
 
 mod swizzle_f32x4;
 mod swizzle_i32x4;
@@ -206,6 +206,7 @@ impl Sub<F32x2> for F32x2 {
 // Four 32-bit floats
 
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct F32x4(pub float32x4_t);
 
 impl F32x4 {
@@ -219,6 +220,54 @@ impl F32x4 {
         F32x4::new(x, x, x, x)
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [f32; 4]) -> F32x4 {
+        unsafe { F32x4(mem::transmute(array)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] {
+        unsafe { mem::transmute(self.0) }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const f32) -> F32x4 {
+        F32x4(*(ptr as *const float32x4_t))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut f32) {
+        *(ptr as *mut float32x4_t) = self.0
+    }
+
+    /// Loads the float at byte address `base + indices[i] * stride_bytes` into each lane `i`.
+    ///
+    /// # Safety
+    ///
+    /// Every computed address must be valid for an unaligned read of an `f32`.
+    #[inline]
+    pub unsafe fn gather_stride(base: *const u8, indices: I32x4, stride_bytes: i32) -> F32x4 {
+        let load = |i: usize| {
+            let offset = indices[i] as isize * stride_bytes as isize;
+            (base.offset(offset) as *const f32).read_unaligned()
+        };
+        F32x4::new(load(0), load(1), load(2), load(3))
+    }
+
     // Basic operations
 
     #[inline]
@@ -226,6 +275,23 @@ impl F32x4 {
         unsafe { F32x4(vrecpe_v4f32(self.0)) }
     }
 
+    #[inline]
+    pub fn approx_rsqrt(self) -> F32x4 {
+        unsafe { F32x4(vrsqrte_v4f32(self.0)) }
+    }
+
+    /// Computes `self * b + c` as a single fused multiply-add, rounded once.
+    #[inline]
+    pub fn mul_add(self, b: F32x4, c: F32x4) -> F32x4 {
+        unsafe { F32x4(aarch64::vfmaq_f32(c.0, self.0, b.0)) }
+    }
+
+    /// Returns the dot product of all four lanes of this vector and `other`.
+    #[inline]
+    pub fn dot(self, other: F32x4) -> f32 {
+        (self * other).sum()
+    }
+
     #[inline]
     pub fn min(self, other: F32x4) -> F32x4 {
         unsafe { F32x4(simd_fmin(self.0, other.0)) }
@@ -236,6 +302,10 @@ impl F32x4 {
         unsafe { F32x4(simd_fmax(self.0, other.0)) }
     }
 
+    /// Clamps each lane to the range `[min, max]`.
+    ///
+    /// `fmaxnm` and `fminnm` ignore NaN operands, so a NaN lane in `self` becomes `min`. The
+    /// result for NaN bounds differs between backends.
     #[inline]
     pub fn clamp(self, min: F32x4, max: F32x4) -> F32x4 {
         self.max(min).min(max)
@@ -256,6 +326,15 @@ impl F32x4 {
         unsafe { F32x4(ceil_v4f32(self.0)) }
     }
 
+    /// Rounds each lane to the nearest integer, with ties going to the even integer, so `2.5`
+    /// rounds to `2.0` and `3.5` rounds to `4.0`.
+    #[inline]
+    pub fn round(self) -> F32x4 {
+        unsafe { F32x4(aarch64::vrndnq_f32(self.0)) }
+    }
+
+    /// Returns the square root of each lane. Negative lanes become NaN, and NaN lanes pass
+    /// through.
     #[inline]
     pub fn sqrt(self) -> F32x4 {
         unsafe { F32x4(sqrt_v4f32(self.0)) }
@@ -283,6 +362,25 @@ impl F32x4 {
         unsafe { U32x4(simd_lt(self.0, other.0)) }
     }
 
+    /// Packs the sign bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        self.to_i32x4_bits().to_u32x4().movemask()
+    }
+
+    // Swizzles
+
+    /// Permutes the lanes as `_mm_shuffle_ps` does: lane `i` of the result is lane
+    /// `(IMM8 >> (2 * i)) & 3` of this vector, so `shuffle::<0b00_01_10_11>()` is `wzyx()`.
+    /// `IMM8` must be in `0..256`; other values fail to compile.
+    #[inline]
+    pub fn shuffle<const IMM8: i32>(self) -> F32x4 {
+        const { assert!(IMM8 >= 0 && IMM8 < 256, "shuffle index out of range") };
+        let lanes = self.to_array();
+        let lane = |i: i32| lanes[((IMM8 >> (2 * i)) & 3) as usize];
+        F32x4::new(lane(0), lane(1), lane(2), lane(3))
+    }
+
     // Swizzle conversions
 
     #[inline]
@@ -334,6 +432,20 @@ impl F32x4 {
     pub fn to_i32x4(self) -> I32x4 {
         unsafe { I32x4(simd_cast(round_v4f32(self.0))) }
     }
+
+    /// Converts these packed floats to integers by truncating toward zero, with the same
+    /// saturating semantics as `as i32`: out-of-range lanes clamp to `i32::MIN` or `i32::MAX`, and
+    /// NaN lanes become 0.
+    #[inline]
+    pub fn to_i32x4_saturating(self) -> I32x4 {
+        unsafe { I32x4(aarch64::vcvtq_s32_f32(self.0)) }
+    }
+
+    /// Reinterprets the bits of these packed floats as integers.
+    #[inline]
+    pub fn to_i32x4_bits(self) -> I32x4 {
+        unsafe { I32x4(mem::transmute(self.0)) }
+    }
 }
 
 impl Default for F32x4 {
@@ -542,6 +654,7 @@ impl Mul<I32x2> for I32x2 {
 // Four 32-bit signed integers
 
 #[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
 pub struct I32x4(pub int32x4_t);
 
 impl I32x4 {
@@ -555,6 +668,40 @@ impl I32x4 {
         I32x4::new(x, x, x, x)
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [i32; 4]) -> I32x4 {
+        unsafe { I32x4(mem::transmute(array)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 4] {
+        unsafe { mem::transmute(self.0) }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const i32) -> I32x4 {
+        I32x4(*(ptr as *const int32x4_t))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut i32) {
+        *(ptr as *mut int32x4_t) = self.0
+    }
+
     // Basic operations
 
     #[inline]
@@ -567,6 +714,27 @@ impl I32x4 {
         unsafe { I32x4(simd_cast(simd_fmin(self.to_f32x4().0, other.to_f32x4().0))) }
     }
 
+    /// Returns the absolute value of each lane. `i32::MIN` has no positive counterpart, so it
+    /// wraps around to itself, as with `i32::wrapping_abs`.
+    #[inline]
+    pub fn abs(self) -> I32x4 {
+        unsafe { I32x4(aarch64::vabsq_s32(self.0)) }
+    }
+
+    /// Multiplies each pair of lanes and returns the high 32 bits of the 64-bit signed products,
+    /// as `((a as i64 * b as i64) >> 32) as i32` does. This scales each lane of `self` by the
+    /// fraction `other / 2³²`.
+    #[inline]
+    pub fn mulhi(self, other: I32x4) -> I32x4 {
+        unsafe {
+            let lo = aarch64::vmull_s32(aarch64::vget_low_s32(self.0),
+                                        aarch64::vget_low_s32(other.0));
+            let hi = aarch64::vmull_high_s32(self.0, other.0);
+            I32x4(aarch64::vuzp2q_s32(aarch64::vreinterpretq_s32_s64(lo),
+                                      aarch64::vreinterpretq_s32_s64(hi)))
+        }
+    }
+
     // Packed comparisons
 
     #[inline]
@@ -584,6 +752,36 @@ impl I32x4 {
         unsafe { U32x4(simd_lt(self.0, other.0)) }
     }
 
+    #[inline]
+    pub fn packed_gt(self, other: I32x4) -> U32x4 {
+        unsafe { U32x4(simd_gt(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn packed_ge(self, other: I32x4) -> U32x4 {
+        unsafe { U32x4(simd_ge(self.0, other.0)) }
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> I32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+        I32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    /// Arithmetically shifts each lane right by the corresponding lane of `counts`, filling with
+    /// copies of the sign bit. Counts of 32 or more fill the whole lane with the sign bit.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> I32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i] >> counts[i].min(31);
+        I32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
     // Concatenations
 
     #[inline]
@@ -630,6 +828,37 @@ impl I32x4 {
     pub fn to_f32x4(self) -> F32x4 {
         unsafe { F32x4(simd_cast(self.0)) }
     }
+
+    /// Reinterprets the bits of these packed integers as floats.
+    #[inline]
+    pub fn to_f32x4_bits(self) -> F32x4 {
+        unsafe { F32x4(mem::transmute(self.0)) }
+    }
+
+    /// Converts these packed signed integers to unsigned integers.
+    ///
+    /// Overflowing values will wrap around.
+    #[inline]
+    pub fn to_u32x4(self) -> U32x4 {
+        unsafe { U32x4(mem::transmute(self.0)) }
+    }
+
+    /// Clamps each lane to `0..=255` and packs the lanes into the bytes of a `u32`, lane 0 in the
+    /// least significant byte. Stored little-endian, the word's bytes are in RGBA order.
+    #[inline]
+    pub fn to_rgba8(self) -> u32 {
+        let lanes = self.to_array();
+        let byte = |i: usize| lanes[i].clamp(0, 255) as u32;
+        byte(0) | (byte(1) << 8) | (byte(2) << 16) | (byte(3) << 24)
+    }
+
+    /// Unpacks the bytes of a `u32` into the lanes, least significant byte in lane 0. This is the
+    /// inverse of `to_rgba8`.
+    #[inline]
+    pub fn from_u8_bytes(bytes: u32) -> I32x4 {
+        let [r, g, b, a] = bytes.to_le_bytes();
+        I32x4::new(r as i32, g as i32, b as i32, a as i32)
+    }
 }
 
 impl Default for I32x4 {
@@ -693,6 +922,18 @@ impl PartialEq for I32x4 {
     }
 }
 
+// Division rounds toward zero, like `i32::wrapping_div`, and is exact over the whole `i32` range.
+// Dividing by zero produces `i32::MIN` rather than panicking.
+impl Div<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn div(self, other: I32x4) -> I32x4 {
+        let div = |a: i32, b: i32| if b == 0 { i32::MIN } else { a.wrapping_div(b) };
+        let (a, b) = (self.to_array(), other.to_array());
+        I32x4::new(div(a[0], b[0]), div(a[1], b[1]), div(a[2], b[2]), div(a[3], b[3]))
+    }
+}
+
 impl BitAnd<I32x4> for I32x4 {
     type Output = I32x4;
     #[inline]
@@ -709,6 +950,45 @@ impl BitOr<I32x4> for I32x4 {
     }
 }
 
+impl BitXor<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn bitxor(self, other: I32x4) -> I32x4 {
+        unsafe { I32x4(simd_xor(self.0, other.0)) }
+    }
+}
+
+impl Not for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn not(self) -> I32x4 {
+        self ^ I32x4::splat(!0)
+    }
+}
+
+impl Shl<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> I32x4 {
+        // `simd_shl` is undefined for amounts of the lane width or more; match x86 instead.
+        if amount >= 32 {
+            return I32x4::splat(0);
+        }
+        unsafe { I32x4(simd_shl(self.0, I32x4::splat(amount as i32).0)) }
+    }
+}
+
+impl Shr<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shr(self, amount: u32) -> I32x4 {
+        // `simd_shr` is undefined for amounts of the lane width or more; match x86 instead, which
+        // fills the lane with the sign bit.
+        let amount = amount.min(31);
+        unsafe { I32x4(simd_shr(self.0, I32x4::splat(amount as i32).0)) }
+    }
+}
+
 impl Shr<I32x4> for I32x4 {
     type Output = I32x4;
     #[inline]
@@ -798,9 +1078,68 @@ impl BitOr<U32x2> for U32x2 {
 // Four 32-bit unsigned integers
 
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct U32x4(pub uint32x4_t);
 
 impl U32x4 {
+    // Constructors
+
+    #[inline]
+    pub fn new(a: u32, b: u32, c: u32, d: u32) -> U32x4 {
+        unsafe { U32x4(mem::transmute([a, b, c, d])) }
+    }
+
+    #[inline]
+    pub fn splat(x: u32) -> U32x4 {
+        U32x4::new(x, x, x, x)
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u32; 4]) -> U32x4 {
+        unsafe { U32x4(mem::transmute(array)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 4] {
+        unsafe { mem::transmute(self.0) }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const u32) -> U32x4 {
+        U32x4(*(ptr as *const uint32x4_t))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut u32) {
+        *(ptr as *mut uint32x4_t) = self.0
+    }
+
+    // Conversions
+
+    /// Converts these packed unsigned integers to signed integers.
+    ///
+    /// Overflowing values will wrap around.
+    #[inline]
+    pub fn to_i32x4(self) -> I32x4 {
+        unsafe { I32x4(mem::transmute(self.0)) }
+    }
+
+    // Basic operations
+
     /// Returns true if all four booleans in this vector are true.
     ///
     /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
@@ -818,6 +1157,52 @@ impl U32x4 {
     pub fn all_false(&self) -> bool {
         unsafe { aarch64::vmaxvq_u32(self.0) == 0 }
     }
+
+    /// Packs the high bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        let lanes = self.to_array();
+        (lanes[0] >> 31) | ((lanes[1] >> 31) << 1) | ((lanes[2] >> 31) << 2) |
+            ((lanes[3] >> 31) << 3)
+    }
+
+    // Selection
+
+    /// Picks each lane from `a` where this mask is true and from `b` where it is false.
+    ///
+    /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+        unsafe { F32x4(aarch64::vbslq_f32(self.0, a.0, b.0)) }
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> U32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+        U32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    /// Logically shifts each lane right by the corresponding lane of `counts`. Counts of 32 or
+    /// more produce zero.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> U32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shr(counts[i]).unwrap_or(0);
+        U32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_eq(self.0, other.0)) }
+    }
 }
 
 impl Index<usize> for U32x4 {
@@ -832,6 +1217,312 @@ impl Index<usize> for U32x4 {
     }
 }
 
+impl IndexMut<usize> for U32x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u32 {
+        unsafe {
+            assert!(index < 4);
+            let ptr = &mut self.0 as *mut uint32x4_t as *mut u32;
+            mem::transmute::<*mut u32, &mut u32>(ptr.offset(index as isize))
+        }
+    }
+}
+
+// Unsigned arithmetic wraps on overflow, like `u32::wrapping_add` and friends.
+impl Add<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn add(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_add(self.0, other.0)) }
+    }
+}
+
+impl Sub<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn sub(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_sub(self.0, other.0)) }
+    }
+}
+
+impl Mul<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn mul(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_mul(self.0, other.0)) }
+    }
+}
+
+impl Not for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn not(self) -> U32x4 {
+        self ^ U32x4::splat(!0)
+    }
+}
+
+impl BitAnd<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitand(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_and(self.0, other.0)) }
+    }
+}
+
+impl BitOr<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitor(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_or(self.0, other.0)) }
+    }
+}
+
+impl BitXor<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitxor(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(simd_xor(self.0, other.0)) }
+    }
+}
+
+impl Shl<u32> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> U32x4 {
+        // `simd_shl` is undefined for amounts of the lane width or more; match x86 instead.
+        if amount >= 32 {
+            return U32x4::splat(0);
+        }
+        unsafe { U32x4(simd_shl(self.0, U32x4::splat(amount).0)) }
+    }
+}
+
+impl Shr<u32> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn shr(self, amount: u32) -> U32x4 {
+        // `simd_shr` is undefined for amounts of the lane width or more; match x86 instead.
+        if amount >= 32 {
+            return U32x4::splat(0);
+        }
+        unsafe { U32x4(simd_shr(self.0, U32x4::splat(amount).0)) }
+    }
+}
+
+// Sixteen 8-bit unsigned integers
+
+#[derive(Clone, Copy)]
+pub struct U8x16(pub uint8x16_t);
+
+impl U8x16 {
+    // Constructors
+
+    #[inline]
+    pub fn splat(x: u8) -> U8x16 {
+        unsafe { U8x16(aarch64::vdupq_n_u8(x)) }
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u8; 16]) -> U8x16 {
+        unsafe { U8x16(mem::transmute(array)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u8; 16] {
+        unsafe { mem::transmute(self.0) }
+    }
+
+    // Conversions
+
+    /// Reinterprets these bytes as four packed 32-bit signed integers.
+    #[inline]
+    pub fn as_i32x4(self) -> I32x4 {
+        unsafe { I32x4(mem::transmute(self.0)) }
+    }
+
+    /// Clamps each lane of `a`, `b`, `c`, and `d` to `0..=255` and packs them, in that order, into
+    /// the sixteen bytes: `a[0]` lands in byte 0 and `d[3]` in byte 15. This is `I32x4::to_rgba8`
+    /// for four vectors at once.
+    #[inline]
+    pub fn pack_i32x4s(a: I32x4, b: I32x4, c: I32x4, d: I32x4) -> U8x16 {
+        unsafe {
+            let lo = aarch64::vcombine_s16(aarch64::vqmovn_s32(a.0), aarch64::vqmovn_s32(b.0));
+            let hi = aarch64::vcombine_s16(aarch64::vqmovn_s32(c.0), aarch64::vqmovn_s32(d.0));
+            U8x16(aarch64::vcombine_u8(aarch64::vqmovun_s16(lo), aarch64::vqmovun_s16(hi)))
+        }
+    }
+
+    // Basic operations
+
+    /// Returns a vector whose byte `i` is byte `indices[i] & 15` of this vector, or zero if the
+    /// high bit of `indices[i]` is set.
+    #[inline]
+    pub fn shuffle(self, indices: U8x16) -> U8x16 {
+        unsafe {
+            // `tbl` zeroes out-of-range indices, so clear the bits that `pshufb` ignores.
+            let indices = aarch64::vandq_u8(indices.0, aarch64::vdupq_n_u8(0x8f));
+            U8x16(aarch64::vqtbl1q_u8(self.0, indices))
+        }
+    }
+
+    /// Packs the high bit of each byte into the low sixteen bits of an integer, byte 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        let bytes = self.to_array();
+        bytes.iter().enumerate().fold(0, |mask, (i, &byte)| mask | ((byte as u32 >> 7) << i))
+    }
+
+    /// Averages each pair of bytes, rounding halves up: `(a + b + 1) >> 1`.
+    #[inline]
+    pub fn avg(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(aarch64::vrhaddq_u8(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn min(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(aarch64::vminq_u8(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn max(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(aarch64::vmaxq_u8(self.0, other.0)) }
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(simd_eq(self.0, other.0)) }
+    }
+
+    // Filtering
+
+    /// Bilinearly interpolates, byte by byte, between the texels `tl`, `tr`, `bl`, and `br` at the
+    /// top left, top right, bottom left, and bottom right of a square. With RGBA8 data this
+    /// filters four pixels at once.
+    ///
+    /// The weights are fixed-point fractions with 8 fractional bits: 0 selects the left or top
+    /// texels, 256 selects the right or bottom ones, and 128 is halfway between. Weights above 256
+    /// are treated as 256. Each byte is interpolated horizontally and then vertically in 16-bit
+    /// lanes, rounding to nearest after each step.
+    #[inline]
+    pub fn bilinear_blend(tl: U8x16, tr: U8x16, bl: U8x16, br: U8x16, wx: u16, wy: u16)
+                          -> U8x16 {
+        let (wx, wy) = (wx.min(256), wy.min(256));
+        tl.lerp_fixed(tr, wx).lerp_fixed(bl.lerp_fixed(br, wx), wy)
+    }
+
+    // Computes `(self × (256 - weight) + other × weight + 128) >> 8` for each byte. `weight` must
+    // be at most 256.
+    #[inline]
+    fn lerp_fixed(self, other: U8x16, weight: u16) -> U8x16 {
+        unsafe {
+            let lerp = |a, b| {
+                let sum = aarch64::vmulq_n_u16(a, 256 - weight);
+                // A rounding shift adds 128 before shifting right by 8.
+                aarch64::vrshrq_n_u16::<8>(aarch64::vmlaq_n_u16(sum, b, weight))
+            };
+            let lo = lerp(aarch64::vmovl_u8(aarch64::vget_low_u8(self.0)),
+                          aarch64::vmovl_u8(aarch64::vget_low_u8(other.0)));
+            let hi = lerp(aarch64::vmovl_high_u8(self.0), aarch64::vmovl_high_u8(other.0));
+            U8x16(aarch64::vcombine_u8(aarch64::vmovn_u16(lo), aarch64::vmovn_u16(hi)))
+        }
+    }
+}
+
+impl Debug for U8x16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let bytes = self.to_array();
+        write!(f, "<{}", bytes[0])?;
+        for byte in &bytes[1..] {
+            write!(f, ", {}", byte)?;
+        }
+        write!(f, ">")
+    }
+}
+
+impl Index<usize> for U8x16 {
+    type Output = u8;
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        unsafe {
+            assert!(index < 16);
+            let ptr = &self.0 as *const uint8x16_t as *const u8;
+            mem::transmute::<*const u8, &u8>(ptr.offset(index as isize))
+        }
+    }
+}
+
+impl IndexMut<usize> for U8x16 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        unsafe {
+            assert!(index < 16);
+            let ptr = &mut self.0 as *mut uint8x16_t as *mut u8;
+            mem::transmute::<*mut u8, &mut u8>(ptr.offset(index as isize))
+        }
+    }
+}
+
+impl PartialEq for U8x16 {
+    #[inline]
+    fn eq(&self, other: &U8x16) -> bool {
+        self.packed_eq(*other).movemask() == 0xffff
+    }
+}
+
+// Byte arithmetic saturates instead of wrapping, since that is what pixel and coverage math wants.
+impl Add<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn add(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(aarch64::vqaddq_u8(self.0, other.0)) }
+    }
+}
+
+impl Sub<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn sub(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(aarch64::vqsubq_u8(self.0, other.0)) }
+    }
+}
+
+impl Not for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn not(self) -> U8x16 {
+        self ^ U8x16::splat(!0)
+    }
+}
+
+impl BitAnd<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitand(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(simd_and(self.0, other.0)) }
+    }
+}
+
+impl BitOr<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitor(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(simd_or(self.0, other.0)) }
+    }
+}
+
+impl BitXor<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitxor(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(simd_xor(self.0, other.0)) }
+    }
+}
+
 // Intrinsics
 
 extern "platform-intrinsic" {
@@ -840,6 +1531,7 @@ extern "platform-intrinsic" {
     fn simd_mul<T>(x: T, y: T) -> T;
     fn simd_sub<T>(x: T, y: T) -> T;
 
+    fn simd_shl<T>(x: T, y: T) -> T;
     fn simd_shr<T>(x: T, y: T) -> T;
 
     fn simd_and<T>(x: T, y: T) -> T;
@@ -850,6 +1542,7 @@ extern "platform-intrinsic" {
     fn simd_fmax<T>(x: T, y: T) -> T;
 
     fn simd_eq<T, U>(x: T, y: T) -> U;
+    fn simd_ge<T, U>(x: T, y: T) -> U;
     fn simd_gt<T, U>(x: T, y: T) -> U;
     fn simd_le<T, U>(x: T, y: T) -> U;
     fn simd_lt<T, U>(x: T, y: T) -> U;
@@ -888,4 +1581,7 @@ extern "C" {
 
     #[link_name = "llvm.aarch64.neon.frecpe.v4f32"]
     fn vrecpe_v4f32(a: float32x4_t) -> float32x4_t;
+
+    #[link_name = "llvm.aarch64.neon.frsqrte.v4f32"]
+    fn vrsqrte_v4f32(a: float32x4_t) -> float32x4_t;
 }
@@ -0,0 +1,26 @@
+// pathfinder/simd/src/bytemuck.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `bytemuck` support, so that slices of vectors can be cast to and from bytes safely.
+
+use crate::default::{F32x4, I32x4, U32x4};
+use ::bytemuck::{Pod, Zeroable};
+
+// In every backend these are transparent wrappers around sixteen bytes of lanes, with no padding,
+// and every bit pattern is a valid vector. All zeroes is the zero vector.
+
+unsafe impl Zeroable for F32x4 {}
+unsafe impl Pod for F32x4 {}
+
+unsafe impl Zeroable for I32x4 {}
+unsafe impl Pod for I32x4 {}
+
+unsafe impl Zeroable for U32x4 {}
+unsafe impl Pod for U32x4 {}
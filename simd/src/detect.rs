@@ -0,0 +1,55 @@
+// pathfinder/simd/src/detect.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Runtime detection of the x86 CPU features that this crate uses.
+//!
+//! The x86 backend assumes SSE4.1 at compile time, so a binary built for a generic x86 target
+//! still executes SSE4.1 instructions and faults on CPUs that lack them. Check `has_sse41` before
+//! entering SIMD code paths in such binaries. On other architectures every function here returns
+//! false.
+
+macro_rules! detect {
+    ($(#[$attr:meta])* $name:ident, $feature:tt) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $name() -> bool {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                is_x86_feature_detected!($feature)
+            }
+            #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+            {
+                false
+            }
+        }
+    };
+}
+
+detect!(
+    /// Returns true if the CPU supports SSE4.1, which the x86 backend requires for `floor`,
+    /// `ceil`, `round`, `dot`, `select`, `I32x4::from_u8_bytes` (and so `srgb_decode_u8`), and
+    /// 32-bit integer multiplication.
+    has_sse41, "sse4.1"
+);
+
+detect!(
+    /// Returns true if the CPU supports AVX, which the 256-bit float types require.
+    has_avx, "avx"
+);
+
+detect!(
+    /// Returns true if the CPU supports AVX2, which the 256-bit integer types require.
+    has_avx2, "avx2"
+);
+
+detect!(
+    /// Returns true if the CPU supports fused multiply-add.
+    has_fma, "fma"
+);
@@ -8,8 +8,13 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::default::{F32x2, F32x4, I32x2, I32x4};
-use std::ops::{AddAssign, MulAssign, Neg, SubAssign};
+use crate::default::{F32x2, F32x4, I32x2, I32x4, U32x4, U8x16};
+use crate::mask::MaskPayload;
+use std::array;
+#[cfg(not(feature = "libm"))]
+use std::f32::consts::{FRAC_PI_2, LN_2, PI};
+use std::iter::{Product, Sum};
+use std::ops::{AddAssign, DivAssign, MulAssign, Neg, SubAssign};
 
 // Two 32-bit floats
 
@@ -92,6 +97,39 @@ impl F32x4 {
         F32x4::new(slice[0], slice[1], slice[2], slice[3])
     }
 
+    // Loads and stores
+
+    /// Loads the first four elements of `slice`.
+    #[inline]
+    pub fn load(slice: &[f32]) -> F32x4 {
+        let mut array = [0.0; 4];
+        array.copy_from_slice(&slice[0..4]);
+        F32x4::from_array(array)
+    }
+
+    /// Stores the lanes into the first four elements of `slice`.
+    #[inline]
+    pub fn store(self, slice: &mut [f32]) {
+        slice[0..4].copy_from_slice(&self.to_array())
+    }
+
+    /// Loads up to four elements from the start of `slice`, filling the remaining lanes with
+    /// zeroes.
+    #[inline]
+    pub fn load_partial(slice: &[f32]) -> F32x4 {
+        let mut array = [0.0; 4];
+        let len = slice.len().min(4);
+        array[0..len].copy_from_slice(&slice[0..len]);
+        F32x4::from_array(array)
+    }
+
+    /// Stores as many lanes as fit, up to four, into the start of `slice`.
+    #[inline]
+    pub fn store_partial(self, slice: &mut [f32]) {
+        let len = slice.len().min(4);
+        slice[0..len].copy_from_slice(&self.to_array()[0..len])
+    }
+
     // Accessors
 
     #[inline]
@@ -114,6 +152,22 @@ impl F32x4 {
         self[3]
     }
 
+    /// Returns lane `index`, or `None` if `index` is out of range.
+    #[inline]
+    pub fn get(self, index: usize) -> Option<f32> {
+        self.to_array().get(index).copied()
+    }
+
+    /// Returns lane `index` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than 4.
+    #[inline]
+    pub unsafe fn get_unchecked(self, index: usize) -> f32 {
+        *self.to_array().get_unchecked(index)
+    }
+
     // Mutators
 
     #[inline]
@@ -136,12 +190,722 @@ impl F32x4 {
         self[3] = w
     }
 
+    // Lane-wise closures
+
+    /// Applies `f` to each lane in turn.
+    ///
+    /// This is not vectorized. It is meant for prototyping and for scalar reference
+    /// implementations in tests.
+    #[inline]
+    pub fn map<F>(self, mut f: F) -> F32x4 where F: FnMut(f32) -> f32 {
+        F32x4::new(f(self[0]), f(self[1]), f(self[2]), f(self[3]))
+    }
+
+    /// Applies `f` to each pair of corresponding lanes in turn.
+    ///
+    /// Like `map`, this is not vectorized.
+    #[inline]
+    pub fn map2<F>(self, other: F32x4, mut f: F) -> F32x4 where F: FnMut(f32, f32) -> f32 {
+        F32x4::new(f(self[0], other[0]),
+                   f(self[1], other[1]),
+                   f(self[2], other[2]),
+                   f(self[3], other[3]))
+    }
+
     // Comparisons
 
     #[inline]
     pub fn approx_eq(self, other: F32x4, epsilon: f32) -> bool {
         (self - other).abs().packed_gt(F32x4::splat(epsilon)).all_false()
     }
+
+    /// Returns a mask of the lanes that are at most `max_ulps` representable floats away from the
+    /// corresponding lanes of `other`.
+    ///
+    /// Positive and negative zero are zero ULPs apart, and lanes containing NaN never match.
+    pub fn eq_ulps(self, other: F32x4, max_ulps: u32) -> U32x4 {
+        // Sign-magnitude bit patterns are monotone in the values they represent, so the distance
+        // in ULPs is the difference of the magnitudes when the signs agree and their sum when they
+        // don't. The sum is never formed, so that nothing can overflow.
+        let (a, b) = (self.to_i32x4_bits(), other.to_i32x4_bits());
+        let magnitude_mask = I32x4::splat(0x7fff_ffff);
+        let (a_magnitude, b_magnitude) = (a & magnitude_mask, b & magnitude_mask);
+        let max_ulps = I32x4::splat(max_ulps.min(0x7fff_ffff) as i32);
+
+        let same_sign = (a >> 31).packed_eq(b >> 31).to_i32x4();
+        let opposite_sign = same_sign.packed_eq(I32x4::default()).to_i32x4();
+        let difference = a_magnitude.max(b_magnitude) - a_magnitude.min(b_magnitude);
+        let same_sign_ok = difference.packed_le(max_ulps).to_i32x4() & same_sign;
+        let opposite_sign_ok =
+            a_magnitude.packed_le(max_ulps - b_magnitude).to_i32x4() & opposite_sign;
+
+        let infinity = I32x4::splat(0x7f80_0000);
+        let not_nan = a_magnitude.packed_le(infinity).to_i32x4() &
+            b_magnitude.packed_le(infinity).to_i32x4();
+        ((same_sign_ok | opposite_sign_ok) & not_nan).to_u32x4()
+    }
+
+    /// Returns a mask of the lanes with `lo <= self <= hi`. NaN lanes are never in range.
+    #[inline]
+    pub fn in_closed_range(self, lo: F32x4, hi: F32x4) -> U32x4 {
+        // `packed_le` is true for NaN on some backends, so use the ordered comparisons instead.
+        self.packed_eq(self) & !self.packed_lt(lo) & !self.packed_gt(hi)
+    }
+
+    /// Returns a mask of the lanes with `lo < self < hi`. NaN lanes are never in range.
+    #[inline]
+    pub fn in_open_range(self, lo: F32x4, hi: F32x4) -> U32x4 {
+        self.packed_gt(lo) & self.packed_lt(hi)
+    }
+
+    /// Maps each lane to an integer key whose unsigned order matches the order of the floats, for
+    /// radix sorting.
+    ///
+    /// `-0.0` gets the key just below that of `0.0`. NaNs with the sign bit clear sort above
+    /// positive infinity, and NaNs with it set sort below negative infinity.
+    #[inline]
+    pub fn radix_key(self) -> U32x4 {
+        // Flip every bit of negative floats, so that larger magnitudes sort lower, and only the
+        // sign bit of positive ones, so that they sort above all the negative ones.
+        let bits = self.to_i32x4_bits();
+        (bits ^ ((bits >> 31) | I32x4::splat(i32::MIN))).to_u32x4()
+    }
+
+    // Clamping
+
+    /// Clamps each lane to the range `[min, max]`, mapping NaN lanes to `min`.
+    ///
+    /// Unlike `clamp`, whose NaN result depends on the operand order of the underlying min and
+    /// max instructions, the NaN outcome here is always the lower bound.
+    #[inline]
+    pub fn clamp_stable(self, min: F32x4, max: F32x4) -> F32x4 {
+        self.packed_eq(self).select(self.clamp(min, max), min)
+    }
+
+    /// Clamps each lane to the range `[0.0, 1.0]`.
+    #[inline]
+    pub fn saturate(self) -> F32x4 {
+        self.clamp(F32x4::default(), F32x4::splat(1.0))
+    }
+
+    // Sign manipulation
+
+    /// Flips the sign of the lanes where `condition` is true, leaving the others untouched. Zeroes
+    /// and NaNs have their sign bits flipped too.
+    #[inline]
+    pub fn negate_if(self, condition: U32x4) -> F32x4 {
+        let sign_bits = condition & U32x4::splat(0x8000_0000);
+        (self.to_i32x4_bits().to_u32x4() ^ sign_bits).to_i32x4().to_f32x4_bits()
+    }
+
+    // Fused operations
+
+    /// Accumulates `a * b` into this vector in place, using `mul_add`.
+    ///
+    /// This is the accumulation primitive for dot products and convolutions: keep the accumulator
+    /// across calls and reduce it to a scalar only once at the end.
+    #[inline]
+    pub fn fma_acc(&mut self, a: F32x4, b: F32x4) {
+        *self = a.mul_add(b, *self)
+    }
+
+    /// Evaluates the polynomial with the given coefficients, constant term first, at each lane
+    /// using Horner's method. An empty slice is the zero polynomial.
+    #[inline]
+    pub fn poly_horner(self, coeffs: &[f32]) -> F32x4 {
+        coeffs.iter().rev().fold(F32x4::default(), |value, &coefficient| {
+            value.mul_add(self, F32x4::splat(coefficient))
+        })
+    }
+
+    // Division
+
+    /// Divides by a divisor whose reciprocal has already been computed, as a single multiply.
+    ///
+    /// When many vectors are divided by the same value, compute `F32x4::splat(1.0 / divisor)`
+    /// once outside the loop and pass it here, trading a division per vector for a multiply. The
+    /// result may differ from true division by an ulp or so.
+    #[inline]
+    pub fn div_by_reciprocal(self, recip_of_divisor: F32x4) -> F32x4 {
+        self * recip_of_divisor
+    }
+
+    /// Divides by `divisor`, substituting the lanes of `fallback` where `divisor` is zero of
+    /// either sign, and also returns the mask of those lanes.
+    ///
+    /// The division is exact, not an approximation through `recip`.
+    #[inline]
+    pub fn safe_div(self, divisor: F32x4, fallback: F32x4) -> (F32x4, U32x4) {
+        let by_zero = divisor.packed_eq(F32x4::default());
+        (by_zero.select(fallback, self / divisor), by_zero)
+    }
+
+    // Approximations
+
+    /// Returns a fast approximation of the reciprocal of each lane, the same as `approx_recip`.
+    ///
+    /// On x86 the relative error is up to 1.5 × 2⁻¹², roughly 12 bits of precision, so this is no
+    /// substitute for `F32x4::splat(1.0) / self` where accuracy matters.
+    #[inline]
+    pub fn recip(self) -> F32x4 {
+        self.approx_recip()
+    }
+
+    /// Returns a fast approximation of the reciprocal square root of each lane, the same as
+    /// `approx_rsqrt`.
+    ///
+    /// On x86 the relative error is up to 1.5 × 2⁻¹², roughly 12 bits of precision, so this is no
+    /// substitute for `F32x4::splat(1.0) / self.sqrt()` where accuracy matters.
+    #[inline]
+    pub fn rsqrt(self) -> F32x4 {
+        self.approx_rsqrt()
+    }
+
+    // Rounding and remainders
+
+    /// Returns the fractional parts of these packed floats, `self - self.floor()`.
+    #[inline]
+    pub fn fract(self) -> F32x4 {
+        self - self.floor()
+    }
+
+    /// Returns the least nonnegative remainders of dividing these packed floats by `divisor`.
+    #[inline]
+    pub fn rem_euclid(self, divisor: F32x4) -> F32x4 {
+        let divisor = divisor.abs();
+        self - divisor * (self / divisor).floor()
+    }
+
+    // Interpolation
+
+    /// Linearly interpolates from `self` to `other` by `t`.
+    ///
+    /// This returns exactly `self` where `t` is 0.0 and exactly `other` where `t` is 1.0, which
+    /// `self + (other - self) * t` does not guarantee. The final multiply-add is fused when FMA is
+    /// available.
+    #[inline]
+    pub fn lerp(self, other: F32x4, t: F32x4) -> F32x4 {
+        other.mul_add(t, self * (F32x4::splat(1.0) - t))
+    }
+
+    /// Interpolates the attributes `a`, `b`, and `c` of a triangle's vertices by the barycentric
+    /// weights in the x, y, and z lanes of `w`. The w lane of `w` is ignored.
+    #[inline]
+    pub fn barycentric(a: F32x4, b: F32x4, c: F32x4, w: F32x4) -> F32x4 {
+        c.mul_add(w.zzzz(), b.mul_add(w.yyyy(), a * w.xxxx()))
+    }
+
+    /// Returns 0.0 in the lanes less than `edge` and 1.0 in the others.
+    #[inline]
+    pub fn step(self, edge: F32x4) -> F32x4 {
+        let mask = edge.packed_le(self).to_i32x4();
+        (F32x4::splat(1.0).to_i32x4_bits() & mask).to_f32x4_bits()
+    }
+
+    /// Performs Hermite interpolation between 0.0 at `edge0` and 1.0 at `edge1`.
+    #[inline]
+    pub fn smoothstep(self, edge0: F32x4, edge1: F32x4) -> F32x4 {
+        let t = ((self - edge0) / (edge1 - edge0)).saturate();
+        t * t * F32x4::splat(-2.0).mul_add(t, F32x4::splat(3.0))
+    }
+
+    /// Returns the derivative of `smoothstep` with respect to `self`, which is zero at and beyond
+    /// both edges.
+    #[inline]
+    pub fn smoothstep_deriv(self, edge0: F32x4, edge1: F32x4) -> F32x4 {
+        let width = edge1 - edge0;
+        let t = ((self - edge0) / width).saturate();
+        F32x4::splat(6.0) * (-t).mul_add(t, t) / width
+    }
+
+    // Easing
+
+    /// Evaluates the cubic ease-in-out curve at each lane, after clamping to `[0.0, 1.0]`.
+    #[inline]
+    pub fn ease_in_out_cubic(self) -> F32x4 {
+        let t = self.saturate();
+        let ease_in = F32x4::splat(4.0) * t * t * t;
+        let u = F32x4::splat(-2.0).mul_add(t, F32x4::splat(2.0));
+        let ease_out = (F32x4::splat(-0.5) * u * u).mul_add(u, F32x4::splat(1.0));
+        t.packed_lt(F32x4::splat(0.5)).select(ease_in, ease_out)
+    }
+
+    /// Evaluates the quadratic ease-out curve at each lane, after clamping to `[0.0, 1.0]`.
+    #[inline]
+    pub fn ease_out_quad(self) -> F32x4 {
+        let t = self.saturate();
+        (-t).mul_add(t, t + t)
+    }
+
+    // Color space conversions
+
+    /// Applies the sRGB decoding curve, mapping encoded values in `[0.0, 1.0]` to linear light.
+    ///
+    /// The power function in the curve is a polynomial approximation with a relative error of
+    /// around 10⁻⁶, or a `libm::powf` call per lane with the `libm` feature.
+    #[inline]
+    pub fn srgb_to_linear(self) -> F32x4 {
+        let linear = self / F32x4::splat(12.92);
+        let curved = ((self + F32x4::splat(0.055)) / F32x4::splat(1.055)).pow_positive(2.4);
+        self.packed_le(F32x4::splat(0.04045)).select(linear, curved)
+    }
+
+    /// Applies the sRGB encoding curve, mapping linear light in `[0.0, 1.0]` to encoded values.
+    /// This is the inverse of `srgb_to_linear`, and is computed the same way.
+    #[inline]
+    pub fn linear_to_srgb(self) -> F32x4 {
+        let linear = self * F32x4::splat(12.92);
+        let curved = F32x4::splat(1.055) * self.pow_positive(1.0 / 2.4) - F32x4::splat(0.055);
+        self.packed_le(F32x4::splat(0.0031308)).select(linear, curved)
+    }
+
+    // Raises each lane to `exponent`. Lanes must be positive and normal; others produce
+    // unspecified values.
+    #[cfg(feature = "libm")]
+    fn pow_positive(self, exponent: f32) -> F32x4 {
+        self.map(|x| ::libm::powf(x, exponent))
+    }
+
+    // Raises each lane to `exponent` as `2^(exponent * log2(self))`, with a relative error of
+    // around 10⁻⁶. Lanes must be positive and normal; others produce unspecified values.
+    #[cfg(not(feature = "libm"))]
+    fn pow_positive(self, exponent: f32) -> F32x4 {
+        // log2(m) for m in [1, 2) is 2/ln(2) × atanh(t), with t = (m - 1) / (m + 1) in [0, 1/3).
+        let (fraction, exponent_bits) = self.frexp();
+        let (m, e) = (fraction + fraction, (exponent_bits - I32x4::splat(1)).to_f32x4());
+        let t = (m - F32x4::splat(1.0)) / (m + F32x4::splat(1.0));
+        let t2 = t * t;
+        let mut series = F32x4::splat(1.0 / 11.0);
+        for &coefficient in &[1.0 / 9.0, 1.0 / 7.0, 1.0 / 5.0, 1.0 / 3.0, 1.0] {
+            series = series.mul_add(t2, F32x4::splat(coefficient));
+        }
+        let log2 = (t * F32x4::splat(2.0 / LN_2)).mul_add(series, e);
+
+        // 2^y is 2^floor(y) × e^(f ln 2), with the fractional part f in [0, 1).
+        let y = log2 * F32x4::splat(exponent);
+        let whole = y.floor();
+        let f = (y - whole) * F32x4::splat(LN_2);
+        let mut taylor = F32x4::splat(1.0 / 40320.0);
+        for &coefficient in &[1.0 / 5040.0, 1.0 / 720.0, 1.0 / 120.0, 1.0 / 24.0, 1.0 / 6.0, 0.5] {
+            taylor = taylor.mul_add(f, F32x4::splat(coefficient));
+        }
+        taylor = taylor.mul_add(f, F32x4::splat(1.0)).mul_add(f, F32x4::splat(1.0));
+        taylor.ldexp(whole.to_i32x4())
+    }
+
+    // Inverse trigonometry
+
+    /// Returns the arccosine of each lane, in radians, with an absolute error of a few ulps of π.
+    ///
+    /// Lanes outside `[-1, 1]` are clamped to it first, so that dot products of unit vectors that
+    /// stray slightly out of range because of rounding give 0 or π rather than NaN.
+    ///
+    /// By default this evaluates a polynomial on all four lanes at once. With the `libm` feature
+    /// it calls `libm::acosf` on each lane instead, which is several times slower but agrees with
+    /// the scalar function exactly.
+    #[inline]
+    pub fn acos(self) -> F32x4 {
+        let x = self.clamp(F32x4::splat(-1.0), F32x4::splat(1.0));
+        #[cfg(feature = "libm")]
+        {
+            x.map(::libm::acosf)
+        }
+        #[cfg(not(feature = "libm"))]
+        {
+            x.acos_clamped()
+        }
+    }
+
+    /// Returns the arcsine of each lane, in radians, with an absolute error of a few ulps of π.
+    ///
+    /// As with `acos`, lanes outside `[-1, 1]` are clamped to it first, and the `libm` feature
+    /// switches to a `libm::asinf` call per lane.
+    #[inline]
+    pub fn asin(self) -> F32x4 {
+        #[cfg(feature = "libm")]
+        {
+            self.clamp(F32x4::splat(-1.0), F32x4::splat(1.0)).map(::libm::asinf)
+        }
+        #[cfg(not(feature = "libm"))]
+        {
+            F32x4::splat(FRAC_PI_2) - self.acos()
+        }
+    }
+
+    // Returns the arccosine of each lane, which must be in `[-1, 1]`.
+    #[cfg(not(feature = "libm"))]
+    fn acos_clamped(self) -> F32x4 {
+        // Abramowitz and Stegun 4.4.46: acos(x) ≈ √(1 - x) × p(x) for x in [0, 1], with an error
+        // of at most 2 × 10⁻⁸. Negative lanes use acos(-x) = π - acos(x).
+        let x = self;
+        let a = x.abs();
+        let mut poly = F32x4::splat(-0.0012624911);
+        for &coefficient in &[0.00667009, -0.017088126, 0.03089188, -0.050174303, 0.08897899,
+                              -0.2145988, FRAC_PI_2] {
+            poly = poly.mul_add(a, F32x4::splat(coefficient));
+        }
+        let acos = (F32x4::splat(1.0) - a).sqrt() * poly;
+        x.packed_lt(F32x4::default()).select(F32x4::splat(PI) - acos, acos)
+    }
+
+    // GLSL aliases
+    //
+    // These mirror the names of the GLSL built-in functions to ease porting shaders. `clamp`
+    // already has its GLSL name.
+
+    /// An alias for `lerp`.
+    #[inline]
+    pub fn mix(self, other: F32x4, t: F32x4) -> F32x4 {
+        self.lerp(other, t)
+    }
+
+    /// An alias for `rem_euclid`.
+    ///
+    /// This matches GLSL `mod` for positive divisors. For negative divisors the result is still
+    /// nonnegative, whereas GLSL takes the sign of the divisor.
+    #[inline]
+    pub fn r#mod(self, divisor: F32x4) -> F32x4 {
+        self.rem_euclid(divisor)
+    }
+
+    /// An alias for `approx_rsqrt`.
+    #[inline]
+    pub fn inversesqrt(self) -> F32x4 {
+        self.approx_rsqrt()
+    }
+
+    /// An alias for `mul_add`.
+    #[inline]
+    pub fn fma(self, b: F32x4, c: F32x4) -> F32x4 {
+        self.mul_add(b, c)
+    }
+
+    // Exponent manipulation
+
+    /// Splits these packed floats into fractions with magnitudes in [0.5, 1) and integral
+    /// exponents, following the semantics of C `frexp`.
+    ///
+    /// Zeroes, infinities, and NaNs are returned unchanged with an exponent of zero. Subnormals are
+    /// normalized first, so their fractions and exponents are exact.
+    pub fn frexp(self) -> (F32x4, I32x4) {
+        // Scale subnormals by 2^64 to bring them into the normal range.
+        let zero = I32x4::default();
+        let subnormal = (self.to_i32x4_bits() & I32x4::splat(0x7f80_0000)).packed_eq(zero);
+        let subnormal = subnormal.to_i32x4();
+        let scale = I32x4::splat(0x3f80_0000) + (subnormal & I32x4::splat(64 << 23));
+        let bits = (self * scale.to_f32x4_bits()).to_i32x4_bits();
+
+        let biased_exponent = (bits >> 23) & I32x4::splat(0xff);
+        let normal = zero.packed_lt(biased_exponent).to_i32x4() &
+            biased_exponent.packed_lt(I32x4::splat(0xff)).to_i32x4();
+        let special = normal.packed_eq(zero).to_i32x4();
+
+        let exponent = biased_exponent - I32x4::splat(126) - (subnormal & I32x4::splat(64));
+        let exponent = exponent & normal;
+        let fraction = (bits & I32x4::splat(!0x7f80_0000)) | I32x4::splat(0x3f00_0000);
+        let fraction = (fraction & normal) | (self.to_i32x4_bits() & special);
+        (fraction.to_f32x4_bits(), exponent)
+    }
+
+    /// Multiplies these packed floats by two raised to the given integral powers, following the
+    /// semantics of C `ldexp`.
+    ///
+    /// Results too large to represent become infinities, and results too small to represent are
+    /// correctly rounded to subnormals or zero.
+    pub fn ldexp(self, exponent: I32x4) -> F32x4 {
+        // This is `scalbnf` with the branches replaced by masks. Every intermediate factor is an
+        // exact power of two, so only the final multiplication can round.
+        let (mut x, mut n) = (self, exponent);
+        for _ in 0..2 {
+            let big = I32x4::splat(127).packed_lt(n).to_i32x4() & I32x4::splat(127);
+            let small = n.packed_lt(I32x4::splat(-126)).to_i32x4() & I32x4::splat(-102);
+            let step = big | small;
+            x *= ((step + I32x4::splat(127)) << 23).to_f32x4_bits();
+            n -= step;
+        }
+        let n = n.max(I32x4::splat(-126)).min(I32x4::splat(127));
+        x * ((n + I32x4::splat(127)) << 23).to_f32x4_bits()
+    }
+
+    /// Multiplies these packed floats by two raised to the given integral powers, like `ldexp`,
+    /// by adding `exponent` directly to each exponent field.
+    ///
+    /// This is exact and needs no multiplies when every lane is normal and stays normal. If any
+    /// lane is zero, subnormal, infinite, or NaN, or would overflow or underflow, the whole vector
+    /// falls back to `ldexp`, so overflow still produces infinities and underflow still rounds to
+    /// subnormals or zero.
+    #[inline]
+    pub fn scale_pow2(self, exponent: I32x4) -> F32x4 {
+        let bits = self.to_i32x4_bits();
+        let biased = (bits >> 23) & I32x4::splat(0xff);
+        let scaled = biased + exponent.max(I32x4::splat(-255)).min(I32x4::splat(255));
+        let (zero, max) = (I32x4::splat(0), I32x4::splat(0xff));
+        let normal = biased.packed_gt(zero) & biased.packed_lt(max) & scaled.packed_gt(zero) &
+            scaled.packed_lt(max);
+        if !normal.all_true() {
+            return self.ldexp(exponent);
+        }
+        ((bits & I32x4::splat(!0x7f80_0000)) | (scaled << 23)).to_f32x4_bits()
+    }
+
+    // Adjacent floats
+
+    /// Returns the least float greater than each lane, like `f32::next_up`.
+    ///
+    /// Both zeroes step to the smallest positive subnormal. NaN and positive infinity are
+    /// returned unchanged.
+    #[inline]
+    pub fn next_up(self) -> F32x4 {
+        let bits = self.to_i32x4_bits();
+        // Clamping the magnitude to infinity keeps the arithmetic below from overflowing on NaN
+        // lanes, which are put back at the end.
+        let magnitude = (bits & I32x4::splat(0x7fff_ffff)).min(I32x4::splat(0x7f80_0000));
+        let one = I32x4::splat(1);
+        let negative = bits.packed_lt(I32x4::default()) & !magnitude.packed_eq(I32x4::default());
+        let toward_zero = (magnitude - one) | I32x4::splat(i32::MIN);
+        let stepped = I32x4::blend(negative, toward_zero, magnitude + one).to_f32x4_bits();
+        let unchanged = !self.packed_eq(self) | self.packed_eq(F32x4::splat(f32::INFINITY));
+        unchanged.select(self, stepped)
+    }
+
+    /// Returns the greatest float less than each lane, like `f32::next_down`.
+    ///
+    /// Both zeroes step to the smallest negative subnormal. NaN and negative infinity are
+    /// returned unchanged.
+    #[inline]
+    pub fn next_down(self) -> F32x4 {
+        let minus_one = F32x4::splat(-1.0);
+        (self * minus_one).next_up() * minus_one
+    }
+
+    // Scans
+
+    /// Returns the running sums of the lanes: `[x, x + y, x + y + z, x + y + z + w]`.
+    #[inline]
+    pub fn prefix_sum(self) -> F32x4 {
+        // Hillis–Steele: add the vector shifted up by one lane, then by two lanes.
+        let sum = self + self.shift_up_one_lane();
+        sum + F32x4::default().concat_xy_xy(sum)
+    }
+
+    /// Returns the running sums of the preceding lanes: `[0, x, x + y, x + y + z]`.
+    #[inline]
+    pub fn exclusive_prefix_sum(self) -> F32x4 {
+        self.prefix_sum().shift_up_one_lane()
+    }
+
+    // Permutations
+
+    /// Returns a vector whose lane `i` is lane `indices[i]` of this vector.
+    ///
+    /// Only the low two bits of each index are used.
+    #[inline]
+    pub fn permute(self, indices: I32x4) -> F32x4 {
+        let lanes = self.to_array();
+        let lane = |i: usize| lanes[(indices[i] & 3) as usize];
+        F32x4::new(lane(0), lane(1), lane(2), lane(3))
+    }
+
+    /// Picks each lane from the eight lanes of `self` followed by `other`: an index in `0..4`
+    /// selects a lane of `self`, and an index in `4..8` selects a lane of `other`.
+    ///
+    /// Only the low three bits of each index are used.
+    #[inline]
+    pub fn permute2(self, other: F32x4, indices: I32x4) -> F32x4 {
+        let from_other = ((indices << 29) >> 31).to_u32x4();
+        from_other.select(other.permute(indices), self.permute(indices))
+    }
+
+    // Horizontal operations
+
+    /// Returns the sum of the four lanes, computed as `(x + z) + (y + w)`.
+    #[inline]
+    pub fn sum(self) -> f32 {
+        let pairs = self + self.zwxy();
+        (pairs + pairs.yxwz()).x()
+    }
+
+    /// Returns the smallest lane.
+    ///
+    /// NaN lanes are ignored in every backend. If all four lanes are NaN, the result is infinity.
+    #[inline]
+    pub fn min_lane(self) -> f32 {
+        let x = self.packed_eq(self).select(self, F32x4::splat(f32::INFINITY));
+        let pairs = x.min(x.zwxy());
+        pairs.min(pairs.yxwz()).x()
+    }
+
+    /// Returns the largest lane.
+    ///
+    /// NaN lanes are ignored in every backend. If all four lanes are NaN, the result is negative
+    /// infinity.
+    #[inline]
+    pub fn max_lane(self) -> f32 {
+        let x = self.packed_eq(self).select(self, F32x4::splat(f32::NEG_INFINITY));
+        let pairs = x.max(x.zwxy());
+        pairs.max(pairs.yxwz()).x()
+    }
+
+    // Norms
+
+    /// Returns the largest absolute value among the x, y, and z lanes, ignoring w.
+    #[inline]
+    pub fn norm_inf3(self) -> f32 {
+        self.xyzz().norm_inf4()
+    }
+
+    /// Returns the largest absolute value among all four lanes.
+    #[inline]
+    pub fn norm_inf4(self) -> f32 {
+        let abs = self.abs();
+        let max = abs.max(abs.zwxy());
+        max.max(max.yxwz()).x()
+    }
+
+    /// Returns the sum of the absolute values of the x, y, and z lanes, ignoring w.
+    #[inline]
+    pub fn norm_l1_3(self) -> f32 {
+        let abs = self.abs();
+        abs.x() + abs.y() + abs.z()
+    }
+
+    // 3D vector operations
+
+    /// Returns the dot product of the xyz lanes of this vector and `other`.
+    #[inline]
+    pub fn dot3(self, other: F32x4) -> f32 {
+        let product = self * other;
+        product.x() + product.y() + product.z()
+    }
+
+    /// Projects the xyz lanes of this vector onto those of `onto`. The w lane of the result is
+    /// zero.
+    ///
+    /// Projecting onto a zero xyz vector returns the zero vector.
+    #[inline]
+    pub fn project_onto3(self, onto: F32x4) -> F32x4 {
+        let length_sq = onto.dot3(onto);
+        if length_sq == 0.0 {
+            return F32x4::default();
+        }
+        let projection = F32x4::splat(self.dot3(onto) / length_sq) * onto;
+        (projection.to_i32x4_bits() & I32x4::new(!0, !0, !0, 0)).to_f32x4_bits()
+    }
+
+    /// Returns the component of this vector perpendicular to `onto`, so that adding it to
+    /// `project_onto3(onto)` gives back this vector. The w lane is left untouched.
+    #[inline]
+    pub fn reject_from3(self, onto: F32x4) -> F32x4 {
+        self - self.project_onto3(onto)
+    }
+
+    // 4D vector operations
+
+    /// Returns the dot product of all four lanes of this vector and `other`. This is the same as
+    /// `dot`, named to match `dot2`.
+    #[inline]
+    pub fn dot4(self, other: F32x4) -> f32 {
+        self.dot(other)
+    }
+
+    /// Returns the root mean square of the four lanes.
+    #[inline]
+    pub fn rms(self) -> f32 {
+        (self.dot4(self) * 0.25).sqrt()
+    }
+
+    // 2D vector operations
+
+    /// Returns the dot product of the xy lanes of this vector and `other`.
+    #[inline]
+    pub fn dot2(self, other: F32x4) -> f32 {
+        let product = self * other;
+        product.x() + product.y()
+    }
+
+    /// Returns the z component of the cross product of the xy lanes of this vector and `other`.
+    ///
+    /// This is twice the signed area of the triangle formed by the origin, this point, and `other`.
+    /// It is positive when the three wind counterclockwise in a y-up coordinate system.
+    #[inline]
+    pub fn signed_area2(self, other: F32x4) -> f32 {
+        let product = self * other.yxwz();
+        product.x() - product.y()
+    }
+
+    /// Returns the squared length of the xy lanes.
+    #[inline]
+    pub fn length2_sq(self) -> f32 {
+        self.dot2(self)
+    }
+
+    /// Returns the length of the xy lanes.
+    #[inline]
+    pub fn length2(self) -> f32 {
+        self.length2_sq().sqrt()
+    }
+
+    /// Scales the xy lanes to unit length, leaving z and w untouched.
+    ///
+    /// A zero xy vector is returned unchanged.
+    #[inline]
+    pub fn normalize2(self) -> F32x4 {
+        // Divide by the larger magnitude first, so that the squared length lies in `[1, 2]` and
+        // neither underflows for tiny vectors nor overflows for huge ones.
+        let scale = self.x().abs().max(self.y().abs());
+        if scale == 0.0 {
+            return self;
+        }
+        let xy = self / F32x4::splat(scale);
+
+        // Refine the reciprocal square root estimate with one Newton-Raphson step.
+        let length_sq = F32x4::splat(xy.length2_sq());
+        let estimate = length_sq.approx_rsqrt();
+        let half = F32x4::splat(0.5);
+        let recip_length =
+            estimate * (F32x4::splat(1.5) - half * length_sq * estimate * estimate);
+        (xy * recip_length).concat_xy_zw(self)
+    }
+
+    /// Converts the xy lanes from Cartesian to polar coordinates, returning
+    /// `[r, theta, z, w]` with `theta` in radians in `[-π, π]`.
+    ///
+    /// The angle of the origin is defined to be 0.
+    #[inline]
+    pub fn to_polar2(self) -> F32x4 {
+        let r = self.x().hypot(self.y());
+        let theta = if r == 0.0 { 0.0 } else { self.y().atan2(self.x()) };
+        F32x4::new(r, theta, self.z(), self.w())
+    }
+
+    /// Converts the xy lanes from polar `[r, theta]` to Cartesian coordinates, leaving z and w
+    /// untouched. This is the inverse of `to_polar2`.
+    #[inline]
+    pub fn from_polar2(self) -> F32x4 {
+        let (sin, cos) = self.y().sin_cos();
+        F32x4::new(self.x() * cos, self.x() * sin, self.z(), self.w())
+    }
+
+    /// Returns the 2×2 matrix of a counterclockwise rotation by `angle` radians, packed in
+    /// row-major order as `[cos, -sin, sin, cos]`.
+    #[inline]
+    pub fn rotation2(angle: f32) -> F32x4 {
+        let (sin, cos) = angle.sin_cos();
+        F32x4::new(cos, -sin, sin, cos)
+    }
+
+    /// Multiplies the xy lanes of `point2` by this row-major 2×2 matrix, as built by `rotation2`,
+    /// leaving the z and w lanes of `point2` untouched.
+    #[inline]
+    pub fn apply_rotation2(self, point2: F32x4) -> F32x4 {
+        let products = self * point2.xyxy();
+        (products.xzxz() + products.ywyw()).concat_xy_zw(point2)
+    }
+
+    #[inline]
+    fn shift_up_one_lane(self) -> F32x4 {
+        (self.xxyz().to_i32x4_bits() & I32x4::new(0, !0, !0, !0)).to_f32x4_bits()
+    }
 }
 
 impl AddAssign for F32x4 {
@@ -165,6 +929,13 @@ impl MulAssign for F32x4 {
     }
 }
 
+impl DivAssign for F32x4 {
+    #[inline]
+    fn div_assign(&mut self, other: F32x4) {
+        *self = *self / other
+    }
+}
+
 impl Neg for F32x4 {
     type Output = F32x4;
     #[inline]
@@ -173,6 +944,43 @@ impl Neg for F32x4 {
     }
 }
 
+impl From<[f32; 4]> for F32x4 {
+    #[inline]
+    fn from(array: [f32; 4]) -> F32x4 {
+        F32x4::from_array(array)
+    }
+}
+
+impl From<F32x4> for [f32; 4] {
+    #[inline]
+    fn from(vector: F32x4) -> [f32; 4] {
+        vector.to_array()
+    }
+}
+
+impl IntoIterator for &F32x4 {
+    type Item = f32;
+    type IntoIter = array::IntoIter<f32, 4>;
+    #[inline]
+    fn into_iter(self) -> array::IntoIter<f32, 4> {
+        IntoIterator::into_iter(self.to_array())
+    }
+}
+
+impl Sum for F32x4 {
+    #[inline]
+    fn sum<I>(iter: I) -> F32x4 where I: Iterator<Item = F32x4> {
+        iter.fold(F32x4::default(), |sum, vector| sum + vector)
+    }
+}
+
+impl Product for F32x4 {
+    #[inline]
+    fn product<I>(iter: I) -> F32x4 where I: Iterator<Item = F32x4> {
+        iter.fold(F32x4::splat(1.0), |product, vector| product * vector)
+    }
+}
+
 // Two 32-bit integers
 
 impl AddAssign for I32x2 {
@@ -207,6 +1015,39 @@ impl Neg for I32x2 {
 // Four 32-bit integers
 
 impl I32x4 {
+    // Loads and stores
+
+    /// Loads the first four elements of `slice`.
+    #[inline]
+    pub fn load(slice: &[i32]) -> I32x4 {
+        let mut array = [0; 4];
+        array.copy_from_slice(&slice[0..4]);
+        I32x4::from_array(array)
+    }
+
+    /// Stores the lanes into the first four elements of `slice`.
+    #[inline]
+    pub fn store(self, slice: &mut [i32]) {
+        slice[0..4].copy_from_slice(&self.to_array())
+    }
+
+    /// Loads up to four elements from the start of `slice`, filling the remaining lanes with
+    /// zeroes.
+    #[inline]
+    pub fn load_partial(slice: &[i32]) -> I32x4 {
+        let mut array = [0; 4];
+        let len = slice.len().min(4);
+        array[0..len].copy_from_slice(&slice[0..len]);
+        I32x4::from_array(array)
+    }
+
+    /// Stores as many lanes as fit, up to four, into the start of `slice`.
+    #[inline]
+    pub fn store_partial(self, slice: &mut [i32]) {
+        let len = slice.len().min(4);
+        slice[0..len].copy_from_slice(&self.to_array()[0..len])
+    }
+
     // Accessors
 
     #[inline]
@@ -228,6 +1069,83 @@ impl I32x4 {
     pub fn w(self) -> i32 {
         self[3]
     }
+
+    /// Returns lane `index`, or `None` if `index` is out of range.
+    #[inline]
+    pub fn get(self, index: usize) -> Option<i32> {
+        self.to_array().get(index).copied()
+    }
+
+    /// Returns lane `index` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than 4.
+    #[inline]
+    pub unsafe fn get_unchecked(self, index: usize) -> i32 {
+        *self.to_array().get_unchecked(index)
+    }
+
+    // Comparisons
+
+    /// Returns a mask of the lanes that differ from the corresponding lanes of `other`.
+    #[inline]
+    pub fn packed_ne(self, other: I32x4) -> U32x4 {
+        !self.packed_eq(other)
+    }
+
+    /// Returns true if every lane equals `k`.
+    #[inline]
+    pub fn all_eq_scalar(self, k: i32) -> bool {
+        self.packed_eq(I32x4::splat(k)).all()
+    }
+
+    /// Returns true if any lane equals `k`.
+    #[inline]
+    pub fn any_eq_scalar(self, k: i32) -> bool {
+        self.packed_eq(I32x4::splat(k)).any()
+    }
+
+    // Clamping
+
+    /// Clamps each lane to the range `[lo, hi]`. The result is `hi` where `lo > hi`.
+    #[inline]
+    pub fn clamp(self, lo: I32x4, hi: I32x4) -> I32x4 {
+        self.max(lo).min(hi)
+    }
+
+    // Overflow handling
+
+    /// Subtracts lane by lane, wrapping around at the bounds of `i32`. This is the same as the
+    /// `-` operator.
+    #[inline]
+    pub fn wrapping_sub(self, other: I32x4) -> I32x4 {
+        self - other
+    }
+
+    /// Subtracts lane by lane, wrapping around at the bounds of `i32`, and also returns a mask of
+    /// the lanes that overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, other: I32x4) -> (I32x4, U32x4) {
+        let difference = self - other;
+        let zero = I32x4::default();
+        // Subtracting a positive number must make the result smaller, and subtracting a negative
+        // number must make it larger.
+        let overflow = (other.packed_gt(zero) & difference.packed_gt(self)) |
+            (other.packed_lt(zero) & difference.packed_lt(self));
+        (difference, overflow)
+    }
+
+    /// Subtracts lane by lane, clamping to `i32::MIN` or `i32::MAX` instead of overflowing.
+    #[inline]
+    pub fn saturating_sub(self, other: I32x4) -> I32x4 {
+        let (difference, overflow) = self.overflowing_sub(other);
+        // Overflow only happens when subtracting a positive number goes below `i32::MIN` or
+        // subtracting a negative number goes above `i32::MAX`.
+        let (min, max) = (I32x4::splat(i32::MIN), I32x4::splat(i32::MAX));
+        let bound = I32x4::blend(other.packed_gt(I32x4::default()), min, max);
+        I32x4::blend(overflow, bound, difference)
+    }
 }
 
 impl AddAssign for I32x4 {
@@ -251,6 +1169,7 @@ impl MulAssign for I32x4 {
     }
 }
 
+// Negation wraps around like subtraction, so `-i32::MIN` is `i32::MIN`.
 impl Neg for I32x4 {
     type Output = I32x4;
     #[inline]
@@ -258,3 +1177,294 @@ impl Neg for I32x4 {
         I32x4::default() - self
     }
 }
+
+impl From<[i32; 4]> for I32x4 {
+    #[inline]
+    fn from(array: [i32; 4]) -> I32x4 {
+        I32x4::from_array(array)
+    }
+}
+
+impl From<I32x4> for [i32; 4] {
+    #[inline]
+    fn from(vector: I32x4) -> [i32; 4] {
+        vector.to_array()
+    }
+}
+
+// Four 32-bit unsigned integers
+
+impl U32x4 {
+    // Loads and stores
+
+    /// Loads the first four elements of `slice`.
+    #[inline]
+    pub fn load(slice: &[u32]) -> U32x4 {
+        let mut array = [0; 4];
+        array.copy_from_slice(&slice[0..4]);
+        U32x4::from_array(array)
+    }
+
+    /// Stores the lanes into the first four elements of `slice`.
+    #[inline]
+    pub fn store(self, slice: &mut [u32]) {
+        slice[0..4].copy_from_slice(&self.to_array())
+    }
+
+    /// Loads up to four elements from the start of `slice`, filling the remaining lanes with
+    /// zeroes.
+    #[inline]
+    pub fn load_partial(slice: &[u32]) -> U32x4 {
+        let mut array = [0; 4];
+        let len = slice.len().min(4);
+        array[0..len].copy_from_slice(&slice[0..len]);
+        U32x4::from_array(array)
+    }
+
+    /// Stores as many lanes as fit, up to four, into the start of `slice`.
+    #[inline]
+    pub fn store_partial(self, slice: &mut [u32]) {
+        let len = slice.len().min(4);
+        slice[0..len].copy_from_slice(&self.to_array()[0..len])
+    }
+
+    // Accessors
+
+    /// Returns lane `index`, or `None` if `index` is out of range.
+    #[inline]
+    pub fn get(self, index: usize) -> Option<u32> {
+        self.to_array().get(index).copied()
+    }
+
+    /// Returns lane `index` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than 4.
+    #[inline]
+    pub unsafe fn get_unchecked(self, index: usize) -> u32 {
+        *self.to_array().get_unchecked(index)
+    }
+
+    // Mask reductions
+
+    /// Returns true if every lane of this mask is true.
+    #[inline]
+    pub fn all(self) -> bool {
+        self.movemask() == 0b1111
+    }
+
+    /// Returns true if any lane of this mask is true.
+    #[inline]
+    pub fn any(self) -> bool {
+        self.movemask() != 0
+    }
+
+    // Comparisons
+
+    /// Returns true if every lane equals `k`.
+    #[inline]
+    pub fn all_eq_scalar(self, k: u32) -> bool {
+        self.packed_eq(U32x4::splat(k)).all()
+    }
+
+    /// Returns true if any lane equals `k`.
+    #[inline]
+    pub fn any_eq_scalar(self, k: u32) -> bool {
+        self.packed_eq(U32x4::splat(k)).any()
+    }
+
+    /// Compares lanes as unsigned integers, returning a mask of the lanes of this vector that are
+    /// greater than those of `other`.
+    #[inline]
+    pub fn packed_gt(self, other: U32x4) -> U32x4 {
+        // There is no unsigned comparison, so flip the sign bits and compare as signed instead.
+        let sign = U32x4::splat(0x8000_0000);
+        (self ^ sign).to_i32x4().packed_gt((other ^ sign).to_i32x4())
+    }
+
+    /// Compares lanes as unsigned integers, returning a mask of the lanes of this vector that are
+    /// less than those of `other`.
+    #[inline]
+    pub fn packed_lt(self, other: U32x4) -> U32x4 {
+        other.packed_gt(self)
+    }
+
+    // Overflow handling
+
+    /// Subtracts lane by lane, wrapping around at zero. This is the same as the `-` operator.
+    #[inline]
+    pub fn wrapping_sub(self, other: U32x4) -> U32x4 {
+        self - other
+    }
+
+    /// Subtracts lane by lane, wrapping around at zero, and also returns a mask of the lanes that
+    /// overflowed.
+    #[inline]
+    pub fn overflowing_sub(self, other: U32x4) -> (U32x4, U32x4) {
+        (self - other, self.packed_lt(other))
+    }
+
+    /// Subtracts lane by lane, clamping to zero instead of overflowing.
+    #[inline]
+    pub fn saturating_sub(self, other: U32x4) -> U32x4 {
+        let (difference, borrow) = self.overflowing_sub(other);
+        difference & !borrow
+    }
+
+    // Selection
+
+    /// Keeps the lanes of `value` where this mask is true and replaces the rest with `default`.
+    ///
+    /// This works for `F32x4`, `I32x4`, and `U32x4` payloads alike.
+    #[inline]
+    pub fn select_or<T>(self, value: T, default: T::Scalar) -> T where T: MaskPayload {
+        T::blend(self, value, T::splat_scalar(default))
+    }
+
+    /// Takes each bit from `a` where the corresponding bit of this vector is set and from `b`
+    /// where it is clear.
+    ///
+    /// Unlike `select`, this vector need not be a mask of booleans: it mixes `a` and `b` bit by
+    /// bit rather than lane by lane. It works for `F32x4`, `I32x4`, and `U32x4` payloads alike.
+    #[inline]
+    pub fn bitselect<T>(self, a: T, b: T) -> T where T: MaskPayload {
+        T::blend_bits(self, a, b)
+    }
+
+    // Bit manipulation
+
+    /// Returns a vector with only bit `n` set in every lane.
+    ///
+    /// `n` must be less than 32.
+    #[inline]
+    pub fn bit(n: u32) -> U32x4 {
+        U32x4::splat(1 << n)
+    }
+
+    /// Returns a mask of the lanes that have bit `n` set.
+    #[inline]
+    pub fn test_bit(self, n: u32) -> U32x4 {
+        let bit = U32x4::bit(n);
+        (self & bit).packed_eq(bit)
+    }
+
+    /// Returns a copy of this vector with bit `n` set in every lane.
+    #[inline]
+    pub fn set_bit(self, n: u32) -> U32x4 {
+        self | U32x4::bit(n)
+    }
+
+    /// Returns a copy of this vector with bit `n` cleared in every lane.
+    #[inline]
+    pub fn clear_bit(self, n: u32) -> U32x4 {
+        self & !U32x4::bit(n)
+    }
+
+    /// Returns a copy of this vector with bit `n` flipped in every lane.
+    #[inline]
+    pub fn toggle_bit(self, n: u32) -> U32x4 {
+        self ^ U32x4::bit(n)
+    }
+
+    // Powers of two
+
+    /// Returns a mask of the lanes that are powers of two. Zero is not a power of two.
+    #[inline]
+    pub fn is_power_of_two(self) -> U32x4 {
+        let zero = U32x4::splat(0);
+        !self.packed_eq(zero) & (self & (self - U32x4::splat(1))).packed_eq(zero)
+    }
+
+    /// Rounds each lane up to the nearest power of two.
+    ///
+    /// Zero rounds up to 1, and lanes greater than `1 << 31` wrap around to 0.
+    #[inline]
+    pub fn next_power_of_two(self) -> U32x4 {
+        let one = U32x4::splat(1);
+        let mut x = (self | (self.packed_eq(U32x4::splat(0)) & one)) - one;
+        x = x | (x >> 1);
+        x = x | (x >> 2);
+        x = x | (x >> 4);
+        x = x | (x >> 8);
+        x = x | (x >> 16);
+        x + one
+    }
+}
+
+impl AddAssign for U32x4 {
+    #[inline]
+    fn add_assign(&mut self, other: U32x4) {
+        *self = *self + other
+    }
+}
+
+impl SubAssign for U32x4 {
+    #[inline]
+    fn sub_assign(&mut self, other: U32x4) {
+        *self = *self - other
+    }
+}
+
+impl MulAssign for U32x4 {
+    #[inline]
+    fn mul_assign(&mut self, other: U32x4) {
+        *self = *self * other
+    }
+}
+
+impl From<[u32; 4]> for U32x4 {
+    #[inline]
+    fn from(array: [u32; 4]) -> U32x4 {
+        U32x4::from_array(array)
+    }
+}
+
+impl From<U32x4> for [u32; 4] {
+    #[inline]
+    fn from(vector: U32x4) -> [u32; 4] {
+        vector.to_array()
+    }
+}
+
+// Sixteen 8-bit unsigned integers
+
+impl U8x16 {
+    // Constructors
+
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8,
+               i: u8, j: u8, k: u8, l: u8, m: u8, n: u8, o: u8, p: u8)
+               -> U8x16 {
+        U8x16::from_array([a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p])
+    }
+
+    // Accessors
+
+    /// Returns lane `index`, or `None` if `index` is out of range.
+    #[inline]
+    pub fn get(self, index: usize) -> Option<u8> {
+        self.to_array().get(index).copied()
+    }
+
+    /// Returns lane `index` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than 16.
+    #[inline]
+    pub unsafe fn get_unchecked(self, index: usize) -> u8 {
+        *self.to_array().get_unchecked(index)
+    }
+
+    // Order statistics
+
+    /// Returns the median of each triple of bytes, as `max(min(a, b), min(max(a, b), c))`. With
+    /// RGBA8 data this takes the per-channel median of four pixels from three frames at once, as
+    /// in temporal denoising.
+    #[inline]
+    pub fn median3(a: U8x16, b: U8x16, c: U8x16) -> U8x16 {
+        a.min(b).max(a.max(b).min(c))
+    }
+}
@@ -0,0 +1,522 @@
+// pathfinder/simd/src/kernels.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Kernels that run SIMD operations over slices.
+
+use crate::default::{F32x4, I32x4, U32x4, U8x16};
+
+// Masks
+
+/// Writes the positions of the set bits of `mask`, in ascending order, into `out` and returns how
+/// many were written.
+///
+/// This turns the result of a `movemask` into the list of matching lane indices. `out` must have
+/// room for at least `mask.count_ones()` entries.
+pub fn mask_to_indices(mut mask: u32, out: &mut [u8]) -> usize {
+    let count = mask.count_ones() as usize;
+    for index in &mut out[0..count] {
+        *index = mask.trailing_zeros() as u8;
+        mask &= mask - 1;
+    }
+    count
+}
+
+// Reductions
+
+/// Returns the per-lane minimum over every four-element chunk of `slice`, so that lane `i` of the
+/// result is the smallest of the elements at `i`, `i + 4`, `i + 8`, and so on.
+///
+/// This suits buffers with a stride-4 layout, such as `[x, y, x, y, ...]` points, where lane
+/// position is significant. `slice.len()` must be a multiple of 4. An empty slice yields infinity
+/// in every lane.
+pub fn reduce_min_lanes(slice: &[f32]) -> F32x4 {
+    assert_eq!(slice.len() % 4, 0, "slice length must be a multiple of 4");
+    slice.chunks_exact(4).fold(F32x4::splat(f32::INFINITY), |min, chunk| {
+        min.min(F32x4::load(chunk))
+    })
+}
+
+/// Returns the per-lane maximum over every four-element chunk of `slice`, so that lane `i` of the
+/// result is the largest of the elements at `i`, `i + 4`, `i + 8`, and so on.
+///
+/// `slice.len()` must be a multiple of 4. An empty slice yields negative infinity in every lane.
+pub fn reduce_max_lanes(slice: &[f32]) -> F32x4 {
+    assert_eq!(slice.len() % 4, 0, "slice length must be a multiple of 4");
+    slice.chunks_exact(4).fold(F32x4::splat(f32::NEG_INFINITY), |max, chunk| {
+        max.max(F32x4::load(chunk))
+    })
+}
+
+/// Returns the root mean square of the elements of `slice`, or 0 if it is empty.
+pub fn rms_slice(slice: &[f32]) -> f32 {
+    if slice.is_empty() {
+        return 0.0;
+    }
+    let mut chunks = slice.chunks_exact(4);
+    let mut sum_sq = F32x4::default();
+    for chunk in &mut chunks {
+        let values = F32x4::load(chunk);
+        sum_sq = values.mul_add(values, sum_sq);
+    }
+    let rest = F32x4::load_partial(chunks.remainder());
+    sum_sq = rest.mul_add(rest, sum_sq);
+    (sum_sq.sum() / slice.len() as f32).sqrt()
+}
+
+// Scans
+
+/// Writes the inclusive running sums of `input` to `output`, which must be the same length, so
+/// that `output[i]` is the sum of `input[0..=i]`.
+///
+/// Each chunk of four is scanned with `F32x4::prefix_sum`, and the running total is carried from
+/// one chunk to the next. The additions happen in a different order than a sequential loop's, so
+/// the results can differ from one by rounding.
+pub fn prefix_sum(input: &[f32], output: &mut [f32]) {
+    assert_eq!(input.len(), output.len());
+    let mut carry = F32x4::default();
+    let mut input_chunks = input.chunks_exact(4);
+    let mut output_chunks = output.chunks_exact_mut(4);
+    for (input_chunk, output_chunk) in (&mut input_chunks).zip(&mut output_chunks) {
+        let sums = F32x4::load(input_chunk).prefix_sum() + carry;
+        sums.store(output_chunk);
+        carry = sums.wwww();
+    }
+    let sums = F32x4::load_partial(input_chunks.remainder()).prefix_sum() + carry;
+    sums.store_partial(output_chunks.into_remainder());
+}
+
+// Polynomials
+
+/// Evaluates the polynomial with the given coefficients, constant term first, at each element of
+/// `xs`, writing the results to `out`, which must be the same length.
+pub fn poly_eval(coeffs: &[f32], xs: &[f32], out: &mut [f32]) {
+    assert_eq!(xs.len(), out.len());
+    let mut xs_chunks = xs.chunks_exact(4);
+    let mut out_chunks = out.chunks_exact_mut(4);
+    for (xs_chunk, out_chunk) in (&mut xs_chunks).zip(&mut out_chunks) {
+        F32x4::load(xs_chunk).poly_horner(coeffs).store(out_chunk);
+    }
+    for (out, &x) in out_chunks.into_remainder().iter_mut().zip(xs_chunks.remainder()) {
+        *out = coeffs.iter().rev().fold(0.0, |value, &coefficient| value * x + coefficient);
+    }
+}
+
+// Blending
+
+/// Sets each element of `dst` to the element of `a` where `mask` is nonzero and to the element of
+/// `b` otherwise. All four slices must have the same length.
+pub fn blend(dst: &mut [f32], a: &[f32], b: &[f32], mask: &[u32]) {
+    assert!(a.len() == dst.len() && b.len() == dst.len() && mask.len() == dst.len());
+    let zero = U32x4::splat(0);
+    let simd_len = dst.len() / 4 * 4;
+    for start in (0..simd_len).step_by(4) {
+        let end = start + 4;
+        let selected = !U32x4::load(&mask[start..end]).packed_eq(zero);
+        let blended = selected.select(F32x4::load(&a[start..end]), F32x4::load(&b[start..end]));
+        blended.store(&mut dst[start..end]);
+    }
+    for index in simd_len..dst.len() {
+        dst[index] = if mask[index] != 0 { a[index] } else { b[index] };
+    }
+}
+
+/// Adds `weight` times each element of `src` to the matching element of `dst`, which must be the
+/// same length. Calling this once per layer composites a weighted sum of layers into `dst`.
+pub fn accumulate_weighted(dst: &mut [f32], src: &[f32], weight: f32) {
+    assert_eq!(dst.len(), src.len());
+    let weight4 = F32x4::splat(weight);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+    let mut src_chunks = src.chunks_exact(4);
+    for (dst_chunk, src_chunk) in (&mut dst_chunks).zip(&mut src_chunks) {
+        weight4.mul_add(F32x4::load(src_chunk), F32x4::load(dst_chunk)).store(dst_chunk);
+    }
+    for (dst, src) in dst_chunks.into_remainder().iter_mut().zip(src_chunks.remainder()) {
+        *dst += weight * src;
+    }
+}
+
+// Linear algebra
+
+/// Computes `y = a * x + y` over the elements at `offset`, `offset + stride`,
+/// `offset + 2 * stride`, and so on, for interleaved buffers such as vertex attributes.
+///
+/// Contiguous data (`stride == 1`) is processed four elements at a time. Any other stride falls
+/// back to a scalar loop: strided elements could be gathered, but `y` would then need a scatter,
+/// which no backend has.
+pub fn saxpy_strided(a: f32, x: &[f32], y: &mut [f32], stride: usize, offset: usize) {
+    assert_eq!(x.len(), y.len());
+    assert!(stride > 0);
+    if offset >= x.len() {
+        return;
+    }
+
+    if stride == 1 {
+        let a4 = F32x4::splat(a);
+        let mut x_chunks = x[offset..].chunks_exact(4);
+        let mut y_chunks = y[offset..].chunks_exact_mut(4);
+        for (x_chunk, y_chunk) in (&mut x_chunks).zip(&mut y_chunks) {
+            a4.mul_add(F32x4::load(x_chunk), F32x4::load(y_chunk)).store(y_chunk);
+        }
+        for (x, y) in x_chunks.remainder().iter().zip(y_chunks.into_remainder()) {
+            *y += a * x;
+        }
+        return;
+    }
+
+    for index in (offset..x.len()).step_by(stride) {
+        y[index] += a * x[index];
+    }
+}
+
+/// Computes the dot product of each pair of xyz triples packed in `a` and `b`, writing one result
+/// per triple to `out`, for example to evaluate N·L per vertex.
+///
+/// Four triples at a time are transposed from `[x, y, z, x, ...]` order into separate x, y, and z
+/// vectors, so each lane holds one dot product. Any remaining triples are handled one by one.
+pub fn dot3_batch(a: &[f32], b: &[f32], out: &mut [f32]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len() * 3);
+    let mut a_chunks = a.chunks_exact(12);
+    let mut b_chunks = b.chunks_exact(12);
+    let mut out_chunks = out.chunks_exact_mut(4);
+    for ((a, b), out) in (&mut a_chunks).zip(&mut b_chunks).zip(&mut out_chunks) {
+        let (a_x, a_y, a_z) = transpose_xyz(a);
+        let (b_x, b_y, b_z) = transpose_xyz(b);
+        a_z.mul_add(b_z, a_y.mul_add(b_y, a_x * b_x)).store(out);
+    }
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+    for ((a, b), out) in a_rest.chunks(3).zip(b_rest.chunks(3)).zip(out_chunks.into_remainder()) {
+        *out = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    }
+}
+
+// Transposes four packed xyz triples into vectors of their x, y, and z components.
+fn transpose_xyz(triples: &[f32]) -> (F32x4, F32x4, F32x4) {
+    let v0 = F32x4::load(&triples[0..4]); // x0 y0 z0 x1
+    let v1 = F32x4::load(&triples[4..8]); // y1 z1 x2 y2
+    let v2 = F32x4::load(&triples[8..12]); // z2 x3 y3 z3
+    let x = v0.xwxw().concat_xy_xy(v2.concat_xy_zw(v1).zyzy());
+    let y = v0.concat_xy_xy(v1).yzyz().concat_xy_xy(v1.concat_zw_zw(v2).yzyz());
+    let z = v1.concat_xy_zw(v0).zyzy().concat_xy_xy(v2.xwxw());
+    (x, y, z)
+}
+
+/// Transposes the 4×4 matrix whose rows are `rows`, returning its columns.
+pub fn transpose_4x4(rows: [F32x4; 4]) -> [F32x4; 4] {
+    let [a, b, c, d] = rows;
+    let (ab_lo, cd_lo) = (a.concat_xy_xy(b).xzyw(), c.concat_xy_xy(d).xzyw()); // a0 b0 a1 b1
+    let (ab_hi, cd_hi) = (a.concat_zw_zw(b).xzyw(), c.concat_zw_zw(d).xzyw()); // a2 b2 a3 b3
+    [
+        ab_lo.concat_xy_xy(cd_lo),
+        ab_lo.concat_zw_zw(cd_lo),
+        ab_hi.concat_xy_xy(cd_hi),
+        ab_hi.concat_zw_zw(cd_hi),
+    ]
+}
+
+/// Writes the transpose of the row-major `rows`×`cols` matrix `src` to `dst`, which becomes a
+/// row-major `cols`×`rows` matrix.
+///
+/// The matrix is transposed in 4×4 blocks with `transpose_4x4`. The rows and columns past the
+/// last multiple of four are copied one element at a time.
+pub fn transpose(src: &[f32], rows: usize, cols: usize, dst: &mut [f32]) {
+    assert_eq!(src.len(), rows * cols);
+    assert_eq!(dst.len(), rows * cols);
+    let (block_rows, block_cols) = (rows / 4 * 4, cols / 4 * 4);
+    for row in (0..block_rows).step_by(4) {
+        for col in (0..block_cols).step_by(4) {
+            let load = |k: usize| F32x4::load(&src[(row + k) * cols + col..]);
+            let columns = transpose_4x4([load(0), load(1), load(2), load(3)]);
+            for (k, column) in columns.iter().enumerate() {
+                column.store(&mut dst[(col + k) * rows + row..]);
+            }
+        }
+    }
+    for row in 0..rows {
+        let first_col = if row < block_rows { block_cols } else { 0 };
+        for col in first_col..cols {
+            dst[col * rows + row] = src[row * cols + col];
+        }
+    }
+}
+
+// Filtering
+
+/// Convolves `input` with an odd-length symmetric `kernel` and writes the result to `output`, for
+/// one pass of a separable blur.
+///
+/// Samples beyond either end of `input` repeat the nearest edge sample (clamp-to-edge). Because
+/// the kernel is assumed to be symmetric, only its center and right half are read, and each pair
+/// of mirrored taps costs a single multiply-add.
+pub fn convolve_symmetric(input: &[f32], output: &mut [f32], kernel: &[f32]) {
+    assert_eq!(input.len(), output.len());
+    assert!(kernel.len() % 2 == 1, "kernel length must be odd");
+    let radius = kernel.len() / 2;
+    for start in (0..output.len()).step_by(4) {
+        let start = start as isize;
+        let mut sum = F32x4::splat(kernel[radius]) * clamped_window(input, start);
+        for offset in 1..=radius {
+            let taps = clamped_window(input, start - offset as isize) +
+                clamped_window(input, start + offset as isize);
+            sum = F32x4::splat(kernel[radius + offset]).mul_add(taps, sum);
+        }
+        sum.store_partial(&mut output[start as usize..]);
+    }
+}
+
+// Loads the four samples starting at `start`, clamping out-of-range indices to the edges.
+fn clamped_window(input: &[f32], start: isize) -> F32x4 {
+    if start >= 0 && start as usize + 4 <= input.len() {
+        return F32x4::load(&input[start as usize..]);
+    }
+    let last = input.len() as isize - 1;
+    let sample = |index: isize| input[(start + index).max(0).min(last) as usize];
+    F32x4::new(sample(0), sample(1), sample(2), sample(3))
+}
+
+// Searching
+
+/// Returns the number of bytes in `haystack` equal to `needle`.
+///
+/// Sixteen bytes are compared at a time, and the matches in each chunk are counted from the
+/// comparison's movemask.
+pub fn count_byte(haystack: &[u8], needle: u8) -> usize {
+    let needles = U8x16::splat(needle);
+    let mut chunks = haystack.chunks_exact(16);
+    let mut count = 0;
+    for chunk in &mut chunks {
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(chunk);
+        count += U8x16::from_array(bytes).packed_eq(needles).movemask().count_ones() as usize;
+    }
+    count + chunks.remainder().iter().filter(|&&byte| byte == needle).count()
+}
+
+// Conversions
+
+/// Converts floats in `[0, 1]` to bytes by scaling by 255 and rounding to nearest, ties to even.
+/// Values outside `[0, 1]` clamp to 0 or 255, and NaN becomes 0. `output` must be the same length
+/// as `input`.
+///
+/// Sixteen floats are converted per iteration as four `I32x4`s, which are packed pairwise to
+/// 16-bit lanes and then to one `U8x16` with saturating packs and stored all at once.
+pub fn f32_to_u8(input: &[f32], output: &mut [u8]) {
+    unit_floats_to_bytes(input, output, |floats| floats)
+}
+
+// Applies `encode` to `input` four floats at a time, then converts the results as `f32_to_u8`
+// does, sixteen at a time. The tail is padded with zeros to a full sixteen floats.
+#[inline]
+fn unit_floats_to_bytes<F>(input: &[f32], output: &mut [u8], encode: F)
+                           where F: Fn(F32x4) -> F32x4 {
+    assert_eq!(input.len(), output.len());
+    let pack = |floats: &[f32]| {
+        let quad = |i: usize| unit_floats_to_i32x4(encode(F32x4::load(&floats[i..i + 4])));
+        U8x16::pack_i32x4s(quad(0), quad(4), quad(8), quad(12)).to_array()
+    };
+    let mut input_chunks = input.chunks_exact(16);
+    let mut output_chunks = output.chunks_exact_mut(16);
+    for (input_chunk, output_chunk) in (&mut input_chunks).zip(&mut output_chunks) {
+        output_chunk.copy_from_slice(&pack(input_chunk));
+    }
+    let (input_rest, output_rest) = (input_chunks.remainder(), output_chunks.into_remainder());
+    if !input_rest.is_empty() {
+        let mut floats = [0.0; 16];
+        floats[0..input_rest.len()].copy_from_slice(input_rest);
+        output_rest.copy_from_slice(&pack(&floats)[0..output_rest.len()]);
+    }
+}
+
+// Scales four floats in `[0, 1]` by 255 and rounds them to integers in `0..=255`.
+#[inline]
+fn unit_floats_to_i32x4(floats: F32x4) -> I32x4 {
+    // Adding and subtracting 1.5 × 2²³ rounds to the nearest integer, ties to even, in every
+    // backend, independent of how each one converts floats to integers.
+    let magic = F32x4::splat(12_582_912.0);
+    let scaled = floats.clamp_stable(F32x4::default(), F32x4::splat(1.0)) * F32x4::splat(255.0);
+    ((scaled + magic) - magic).to_i32x4()
+}
+
+/// Decodes sRGB-encoded bytes, such as the color channels of an RGBA8 image, to linear floats
+/// in `[0, 1]`. `output` must be the same length as `input`.
+///
+/// Sixteen bytes are decoded per iteration.
+pub fn srgb_decode_u8(input: &[u8], output: &mut [f32]) {
+    assert_eq!(input.len(), output.len());
+    let mut input_chunks = input.chunks_exact(16);
+    let mut output_chunks = output.chunks_exact_mut(16);
+    for (input_chunk, output_chunk) in (&mut input_chunks).zip(&mut output_chunks) {
+        for (bytes, floats) in input_chunk.chunks_exact(4).zip(output_chunk.chunks_exact_mut(4)) {
+            let mut word = [0; 4];
+            word.copy_from_slice(bytes);
+            bytes_to_linear_floats(u32::from_le_bytes(word)).store(floats);
+        }
+    }
+    let (input_rest, output_rest) = (input_chunks.remainder(), output_chunks.into_remainder());
+    for (bytes, floats) in input_rest.chunks(4).zip(output_rest.chunks_mut(4)) {
+        let mut word = [0; 4];
+        word[0..bytes.len()].copy_from_slice(bytes);
+        bytes_to_linear_floats(u32::from_le_bytes(word)).store_partial(floats);
+    }
+}
+
+/// Encodes linear floats in `[0, 1]` to sRGB bytes, the inverse of `srgb_decode_u8`. Values
+/// outside `[0, 1]` clamp to 0 or 255. `output` must be the same length as `input`.
+///
+/// Sixteen floats are encoded per iteration and converted to bytes as in `f32_to_u8`.
+pub fn srgb_encode_f32(input: &[f32], output: &mut [u8]) {
+    unit_floats_to_bytes(input, output, |floats| floats.saturate().linear_to_srgb())
+}
+
+// Expands the four sRGB-encoded bytes of a `u32`, lowest first, to linear floats.
+fn bytes_to_linear_floats(bytes: u32) -> F32x4 {
+    (I32x4::from_u8_bytes(bytes).to_f32x4() / F32x4::splat(255.0)).srgb_to_linear()
+}
+
+// Images
+
+/// Adds the number of occurrences of each byte value in `pixels` to `bins`.
+///
+/// Consecutive equal bytes make a single table serialize on its own load-increment-store chain,
+/// and x86 has no conflict-free scatter before AVX-512. So the bytes of each 16-byte chunk are
+/// spread round-robin over four private tables, which are summed at the end.
+pub fn histogram_u8(pixels: &[u8], bins: &mut [u32; 256]) {
+    let mut tables = [[0u32; 256]; 4];
+    let mut chunks = pixels.chunks_exact(16);
+    for chunk in &mut chunks {
+        for quad in chunk.chunks_exact(4) {
+            tables[0][quad[0] as usize] += 1;
+            tables[1][quad[1] as usize] += 1;
+            tables[2][quad[2] as usize] += 1;
+            tables[3][quad[3] as usize] += 1;
+        }
+    }
+    for &pixel in chunks.remainder() {
+        tables[0][pixel as usize] += 1;
+    }
+    for (value, bin) in bins.iter_mut().enumerate() {
+        *bin += tables[0][value] + tables[1][value] + tables[2][value] + tables[3][value];
+    }
+}
+
+// Transposes four RGBA pixels into four bytes of each channel, and back again, since the 4×4
+// byte transpose is its own inverse.
+const RGBA_TRANSPOSE: [u8; 16] = [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15];
+
+/// Splits interleaved RGBA8 pixels into one plane per channel.
+///
+/// Four pixels are separated at a time with a byte shuffle; any pixels left over are copied one
+/// at a time. Each plane must hold exactly `interleaved.len() / 4` bytes.
+pub fn deinterleave_rgba(interleaved: &[u8],
+                         r: &mut [u8],
+                         g: &mut [u8],
+                         b: &mut [u8],
+                         a: &mut [u8]) {
+    let pixel_count = r.len();
+    assert_eq!(interleaved.len(), pixel_count * 4);
+    assert!(g.len() == pixel_count && b.len() == pixel_count && a.len() == pixel_count);
+
+    let transpose = U8x16::from_array(RGBA_TRANSPOSE);
+    let simd_pixel_count = pixel_count / 4 * 4;
+    for start in (0..simd_pixel_count).step_by(4) {
+        let mut pixels = [0; 16];
+        pixels.copy_from_slice(&interleaved[start * 4..start * 4 + 16]);
+        let planar = U8x16::from_array(pixels).shuffle(transpose).to_array();
+        r[start..start + 4].copy_from_slice(&planar[0..4]);
+        g[start..start + 4].copy_from_slice(&planar[4..8]);
+        b[start..start + 4].copy_from_slice(&planar[8..12]);
+        a[start..start + 4].copy_from_slice(&planar[12..16]);
+    }
+    for index in simd_pixel_count..pixel_count {
+        let pixel = &interleaved[index * 4..index * 4 + 4];
+        r[index] = pixel[0];
+        g[index] = pixel[1];
+        b[index] = pixel[2];
+        a[index] = pixel[3];
+    }
+}
+
+/// Merges one plane per channel into interleaved RGBA8 pixels. This is the inverse of
+/// `deinterleave_rgba`.
+pub fn interleave_rgba(r: &[u8], g: &[u8], b: &[u8], a: &[u8], interleaved: &mut [u8]) {
+    let pixel_count = r.len();
+    assert_eq!(interleaved.len(), pixel_count * 4);
+    assert!(g.len() == pixel_count && b.len() == pixel_count && a.len() == pixel_count);
+
+    let transpose = U8x16::from_array(RGBA_TRANSPOSE);
+    let simd_pixel_count = pixel_count / 4 * 4;
+    for start in (0..simd_pixel_count).step_by(4) {
+        let mut planar = [0; 16];
+        planar[0..4].copy_from_slice(&r[start..start + 4]);
+        planar[4..8].copy_from_slice(&g[start..start + 4]);
+        planar[8..12].copy_from_slice(&b[start..start + 4]);
+        planar[12..16].copy_from_slice(&a[start..start + 4]);
+        let pixels = U8x16::from_array(planar).shuffle(transpose).to_array();
+        interleaved[start * 4..start * 4 + 16].copy_from_slice(&pixels);
+    }
+    for index in simd_pixel_count..pixel_count {
+        let pixel = &mut interleaved[index * 4..index * 4 + 4];
+        pixel.copy_from_slice(&[r[index], g[index], b[index], a[index]]);
+    }
+}
+
+/// Halves the width and height of an RGBA8 image by averaging each 2×2 block of pixels.
+///
+/// `src` holds `src_width × src_height` pixels, row by row. Each channel is averaged vertically
+/// and then horizontally, rounding halves up at each step as `U8x16::avg` does, so a result can
+/// be one more than the exactly rounded mean. When a dimension is odd, the last column or row is
+/// averaged with itself, making the destination `(src_width + 1) / 2` by `(src_height + 1) / 2`
+/// pixels; `dst` must be exactly that size.
+pub fn downscale_2x_rgba(src: &[u8], src_width: usize, src_height: usize, dst: &mut [u8]) {
+    let (dst_width, dst_height) = (src_width.div_ceil(2), src_height.div_ceil(2));
+    assert_eq!(src.len(), src_width * src_height * 4);
+    assert_eq!(dst.len(), dst_width * dst_height * 4);
+    if dst.is_empty() {
+        return;
+    }
+    let (even, odd) = (U8x16::from_array(EVEN_PIXELS), U8x16::from_array(ODD_PIXELS));
+    for (y, dst_row) in dst.chunks_exact_mut(dst_width * 4).enumerate() {
+        let top = &src[y * 2 * src_width * 4..][..src_width * 4];
+        let bottom = &src[(y * 2 + 1).min(src_height - 1) * src_width * 4..][..src_width * 4];
+
+        // Four source pixels make two destination pixels per iteration.
+        let simd_width = src_width / 4 * 4;
+        let pairs = top[0..simd_width * 4].chunks_exact(16).zip(bottom.chunks_exact(16));
+        for ((top, bottom), dst) in pairs.zip(dst_row.chunks_exact_mut(8)) {
+            let (mut top_bytes, mut bottom_bytes) = ([0; 16], [0; 16]);
+            top_bytes.copy_from_slice(top);
+            bottom_bytes.copy_from_slice(bottom);
+            let column = U8x16::from_array(top_bytes).avg(U8x16::from_array(bottom_bytes));
+            dst.copy_from_slice(&column.shuffle(even).avg(column.shuffle(odd)).to_array()[0..8]);
+        }
+
+        for x in simd_width / 2..dst_width {
+            let (left, right) = (x * 2 * 4, (x * 2 + 1).min(src_width - 1) * 4);
+            for channel in 0..4 {
+                let left = avg_u8(top[left + channel], bottom[left + channel]);
+                let right = avg_u8(top[right + channel], bottom[right + channel]);
+                dst_row[x * 4 + channel] = avg_u8(left, right);
+            }
+        }
+    }
+}
+
+// Gather the even and odd pixels of four RGBA8 pixels into the low eight bytes.
+const EVEN_PIXELS: [u8; 16] = [
+    0, 1, 2, 3, 8, 9, 10, 11, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+];
+const ODD_PIXELS: [u8; 16] = [
+    4, 5, 6, 7, 12, 13, 14, 15, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+];
+
+// Averages two bytes with the same rounding as `U8x16::avg`.
+fn avg_u8(a: u8, b: u8) -> u8 {
+    ((a as u32 + b as u32 + 1) >> 1) as u8
+}
@@ -20,22 +20,53 @@ pub use crate::arm as default;
     not(any(
         target_arch = "x86",
         target_arch = "x86_64",
-        all(pf_rustc_nightly, target_arch = "aarch64")
+        all(pf_rustc_nightly, target_arch = "aarch64"),
+        all(target_arch = "wasm32", target_feature = "simd128")
     ))
 ))]
 pub use crate::scalar as default;
+#[cfg(all(
+    not(feature = "pf-no-simd"),
+    target_arch = "wasm32",
+    target_feature = "simd128"
+))]
+pub use crate::wasm as default;
 #[cfg(all(
     not(feature = "pf-no-simd"),
     any(target_arch = "x86", target_arch = "x86_64")
 ))]
 pub use crate::x86 as default;
 
+pub use crate::kernels::*;
+pub use crate::mask::{MaskPayload, SimdMask};
+pub use crate::ops::SimdF32x4;
+pub use crate::stats::F32Stats;
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+pub use crate::x86_avx::{F32x8, U32x8};
+#[cfg(all(feature = "avx", target_feature = "avx2"))]
+pub use crate::x86_avx::I32x8;
+
 #[cfg(all(pf_rustc_nightly, target_arch = "aarch64"))]
 pub mod arm;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+pub mod detect;
 mod extras;
+mod kernels;
+mod mask;
+#[cfg(feature = "mint")]
+mod mint;
+mod ops;
 pub mod scalar;
+#[cfg(feature = "serde")]
+mod serde;
+mod stats;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub mod wasm;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub mod x86;
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+mod x86_avx;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,171 @@
+// pathfinder/simd/src/mask.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A common interface to the boolean vectors produced by packed comparisons.
+
+use crate::default::{F32x4, I32x4, U32x4, U8x16};
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+use crate::x86_avx::U32x8;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+/// A vector of booleans, one per lane.
+///
+/// A boolean is a lane with all bits set or all bits clear (i.e. !0 or 0). The results of the
+/// methods here are *undefined* if any lane is not a boolean.
+///
+/// This trait is sealed: it is implemented for the mask types of this crate only.
+pub trait SimdMask:
+    Copy
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + private::Sealed
+{
+    /// The number of lanes.
+    const LANES: u32;
+
+    /// Packs the lanes into the low bits of an integer, lane 0 first.
+    fn movemask(self) -> u32;
+
+    /// Returns true if every lane is true.
+    #[inline]
+    fn all(self) -> bool {
+        self.movemask() == (!0 >> (32 - Self::LANES))
+    }
+
+    /// Returns true if any lane is true.
+    #[inline]
+    fn any(self) -> bool {
+        self.movemask() != 0
+    }
+
+    /// Returns the number of true lanes.
+    #[inline]
+    fn count_true(self) -> u32 {
+        self.movemask().count_ones()
+    }
+}
+
+impl SimdMask for U32x4 {
+    const LANES: u32 = 4;
+
+    #[inline]
+    fn movemask(self) -> u32 {
+        U32x4::movemask(self)
+    }
+}
+
+impl SimdMask for U8x16 {
+    const LANES: u32 = 16;
+
+    #[inline]
+    fn movemask(self) -> u32 {
+        U8x16::movemask(self)
+    }
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+impl SimdMask for U32x8 {
+    const LANES: u32 = 8;
+
+    #[inline]
+    fn movemask(self) -> u32 {
+        U32x8::movemask(self)
+    }
+}
+
+/// A four-lane vector that a `U32x4` mask can choose lanes from.
+///
+/// This trait is sealed: it is implemented for `F32x4`, `I32x4`, and `U32x4` only.
+pub trait MaskPayload: Copy + private::Sealed {
+    /// The type of a single lane.
+    type Scalar;
+
+    /// Picks each lane from `a` where `mask` is true and from `b` where it is false.
+    fn blend(mask: U32x4, a: Self, b: Self) -> Self;
+
+    /// Takes each bit from `a` where the corresponding bit of `mask` is set and from `b` where it
+    /// is clear.
+    fn blend_bits(mask: U32x4, a: Self, b: Self) -> Self;
+
+    /// Returns a vector with `value` in every lane.
+    fn splat_scalar(value: Self::Scalar) -> Self;
+}
+
+impl MaskPayload for F32x4 {
+    type Scalar = f32;
+
+    #[inline]
+    fn blend(mask: U32x4, a: F32x4, b: F32x4) -> F32x4 {
+        mask.select(a, b)
+    }
+
+    #[inline]
+    fn blend_bits(mask: U32x4, a: F32x4, b: F32x4) -> F32x4 {
+        let (a, b) = (a.to_i32x4_bits().to_u32x4(), b.to_i32x4_bits().to_u32x4());
+        U32x4::blend_bits(mask, a, b).to_i32x4().to_f32x4_bits()
+    }
+
+    #[inline]
+    fn splat_scalar(value: f32) -> F32x4 {
+        F32x4::splat(value)
+    }
+}
+
+impl MaskPayload for I32x4 {
+    type Scalar = i32;
+
+    #[inline]
+    fn blend(mask: U32x4, a: I32x4, b: I32x4) -> I32x4 {
+        mask.select(a.to_f32x4_bits(), b.to_f32x4_bits()).to_i32x4_bits()
+    }
+
+    #[inline]
+    fn blend_bits(mask: U32x4, a: I32x4, b: I32x4) -> I32x4 {
+        U32x4::blend_bits(mask, a.to_u32x4(), b.to_u32x4()).to_i32x4()
+    }
+
+    #[inline]
+    fn splat_scalar(value: i32) -> I32x4 {
+        I32x4::splat(value)
+    }
+}
+
+impl MaskPayload for U32x4 {
+    type Scalar = u32;
+
+    #[inline]
+    fn blend(mask: U32x4, a: U32x4, b: U32x4) -> U32x4 {
+        I32x4::blend(mask, a.to_i32x4(), b.to_i32x4()).to_u32x4()
+    }
+
+    #[inline]
+    fn blend_bits(mask: U32x4, a: U32x4, b: U32x4) -> U32x4 {
+        // One operation shorter than `(mask & a) | (!mask & b)`.
+        b ^ (mask & (a ^ b))
+    }
+
+    #[inline]
+    fn splat_scalar(value: u32) -> U32x4 {
+        U32x4::splat(value)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::default::F32x4 {}
+    impl Sealed for crate::default::I32x4 {}
+    impl Sealed for crate::default::U32x4 {}
+    impl Sealed for crate::default::U8x16 {}
+    #[cfg(all(feature = "avx", target_feature = "avx"))]
+    impl Sealed for crate::x86_avx::U32x8 {}
+}
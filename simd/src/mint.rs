@@ -0,0 +1,60 @@
+// pathfinder/simd/src/mint.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between `F32x4` and the `mint` interchange types.
+
+use crate::default::F32x4;
+use ::mint::{Point3, Vector3, Vector4};
+
+impl From<Vector4<f32>> for F32x4 {
+    #[inline]
+    fn from(vector: Vector4<f32>) -> F32x4 {
+        F32x4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl From<F32x4> for Vector4<f32> {
+    #[inline]
+    fn from(vector: F32x4) -> Vector4<f32> {
+        Vector4 { x: vector.x(), y: vector.y(), z: vector.z(), w: vector.w() }
+    }
+}
+
+/// Points convert with a w of 1, so that they are affected by translation.
+impl From<Point3<f32>> for F32x4 {
+    #[inline]
+    fn from(point: Point3<f32>) -> F32x4 {
+        F32x4::new(point.x, point.y, point.z, 1.0)
+    }
+}
+
+/// The w lane is dropped.
+impl From<F32x4> for Point3<f32> {
+    #[inline]
+    fn from(vector: F32x4) -> Point3<f32> {
+        Point3 { x: vector.x(), y: vector.y(), z: vector.z() }
+    }
+}
+
+/// Vectors convert with a w of 0, so that they are unaffected by translation.
+impl From<Vector3<f32>> for F32x4 {
+    #[inline]
+    fn from(vector: Vector3<f32>) -> F32x4 {
+        F32x4::new(vector.x, vector.y, vector.z, 0.0)
+    }
+}
+
+/// The w lane is dropped.
+impl From<F32x4> for Vector3<f32> {
+    #[inline]
+    fn from(vector: F32x4) -> Vector3<f32> {
+        Vector3 { x: vector.x(), y: vector.y(), z: vector.z() }
+    }
+}
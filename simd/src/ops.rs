@@ -0,0 +1,103 @@
+// pathfinder/simd/src/ops.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A common interface to four-lane float vectors, for code that is generic over backends.
+
+use crate::default::{F32x4, U32x4};
+use crate::mask::SimdMask;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The operations that every backend's four-lane float vector supports.
+pub trait SimdF32x4:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The boolean vector produced by packed comparisons.
+    type Mask: SimdMask;
+
+    /// Returns a vector with `x` in every lane.
+    fn splat(x: f32) -> Self;
+
+    /// Returns the lesser of each pair of lanes. Which operand comes back when a lane is NaN
+    /// differs between backends.
+    fn min(self, other: Self) -> Self;
+    /// Returns the greater of each pair of lanes, with the same NaN caveat as `min`.
+    fn max(self, other: Self) -> Self;
+    /// Clears the sign bit of each lane.
+    fn abs(self) -> Self;
+
+    /// Returns true in each lane where `self == other`. NaN lanes compare unequal.
+    fn packed_eq(self, other: Self) -> Self::Mask;
+    /// Returns true in each lane where `self > other`. NaN lanes compare false.
+    fn packed_gt(self, other: Self) -> Self::Mask;
+    /// Returns true in each lane where `self < other`. NaN lanes compare false.
+    fn packed_lt(self, other: Self) -> Self::Mask;
+    /// Returns true in each lane where `self <= other`.
+    ///
+    /// NaN lanes are not consistent across backends: x86 computes this as the negation of
+    /// `packed_gt`, so it is true where either lane is NaN, while the other backends compare
+    /// false there. Generic code that may see NaNs should use `packed_lt` or `packed_gt` instead.
+    fn packed_le(self, other: Self) -> Self::Mask;
+
+    /// Picks each lane from `a` where `mask` is true and from `b` where it is false.
+    fn select(mask: Self::Mask, a: Self, b: Self) -> Self;
+}
+
+impl SimdF32x4 for F32x4 {
+    type Mask = U32x4;
+
+    #[inline]
+    fn splat(x: f32) -> F32x4 {
+        F32x4::splat(x)
+    }
+
+    #[inline]
+    fn min(self, other: F32x4) -> F32x4 {
+        F32x4::min(self, other)
+    }
+
+    #[inline]
+    fn max(self, other: F32x4) -> F32x4 {
+        F32x4::max(self, other)
+    }
+
+    #[inline]
+    fn abs(self) -> F32x4 {
+        F32x4::abs(self)
+    }
+
+    #[inline]
+    fn packed_eq(self, other: F32x4) -> U32x4 {
+        F32x4::packed_eq(self, other)
+    }
+
+    #[inline]
+    fn packed_gt(self, other: F32x4) -> U32x4 {
+        F32x4::packed_gt(self, other)
+    }
+
+    #[inline]
+    fn packed_lt(self, other: F32x4) -> U32x4 {
+        F32x4::packed_lt(self, other)
+    }
+
+    #[inline]
+    fn packed_le(self, other: F32x4) -> U32x4 {
+        F32x4::packed_le(self, other)
+    }
+
+    #[inline]
+    fn select(mask: U32x4, a: F32x4, b: F32x4) -> F32x4 {
+        mask.select(a, b)
+    }
+}
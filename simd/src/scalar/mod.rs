@@ -10,7 +10,7 @@
 
 use std::f32;
 use std::fmt::{self, Debug, Formatter};
-use std::ops::{Add, BitAnd, BitOr, Div, Index, IndexMut, Mul, Shr, Sub, Not};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Shl, Shr, Sub, Not};
 
 mod swizzle_f32x4;
 mod swizzle_i32x4;
@@ -193,6 +193,7 @@ impl Sub<F32x2> for F32x2 {
 // Four 32-bit floats
 
 #[derive(Clone, Copy, Default, PartialEq)]
+#[repr(transparent)]
 pub struct F32x4(pub [f32; 4]);
 
 impl F32x4 {
@@ -206,6 +207,54 @@ impl F32x4 {
         F32x4([x; 4])
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [f32; 4]) -> F32x4 {
+        F32x4(array)
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] {
+        self.0
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const f32) -> F32x4 {
+        F32x4(*(ptr as *const [f32; 4]))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut f32) {
+        *(ptr as *mut [f32; 4]) = self.0
+    }
+
+    /// Loads the float at byte address `base + indices[i] * stride_bytes` into each lane `i`.
+    ///
+    /// # Safety
+    ///
+    /// Every computed address must be valid for an unaligned read of an `f32`.
+    #[inline]
+    pub unsafe fn gather_stride(base: *const u8, indices: I32x4, stride_bytes: i32) -> F32x4 {
+        let load = |i: usize| {
+            let offset = indices[i] as isize * stride_bytes as isize;
+            (base.offset(offset) as *const f32).read_unaligned()
+        };
+        F32x4::new(load(0), load(1), load(2), load(3))
+    }
+
     // Basic operations
 
     #[inline]
@@ -213,6 +262,29 @@ impl F32x4 {
         F32x4([1.0 / self[0], 1.0 / self[1], 1.0 / self[2], 1.0 / self[3]])
     }
 
+    #[inline]
+    pub fn approx_rsqrt(self) -> F32x4 {
+        F32x4([
+            1.0 / self[0].sqrt(),
+            1.0 / self[1].sqrt(),
+            1.0 / self[2].sqrt(),
+            1.0 / self[3].sqrt(),
+        ])
+    }
+
+    /// Computes `self * b + c`, rounding the product before the addition.
+    #[inline]
+    pub fn mul_add(self, b: F32x4, c: F32x4) -> F32x4 {
+        self * b + c
+    }
+
+    /// Returns the dot product of all four lanes of this vector and `other`.
+    #[inline]
+    pub fn dot(self, other: F32x4) -> f32 {
+        let product = self * other;
+        (product[0] + product[2]) + (product[1] + product[3])
+    }
+
     #[inline]
     pub fn min(self, other: F32x4) -> F32x4 {
         F32x4([
@@ -233,6 +305,10 @@ impl F32x4 {
         ])
     }
 
+    /// Clamps each lane to the range `[min, max]`.
+    ///
+    /// `f32::max` and `f32::min` ignore NaN operands, so a NaN lane in `self` becomes `min`. The
+    /// result for NaN bounds differs between backends.
     #[inline]
     pub fn clamp(self, min: F32x4, max: F32x4) -> F32x4 {
         self.max(min).min(max)
@@ -263,6 +339,20 @@ impl F32x4 {
         ])
     }
 
+    /// Rounds each lane to the nearest integer, with ties going to the even integer, so `2.5`
+    /// rounds to `2.0` and `3.5` rounds to `4.0`.
+    #[inline]
+    pub fn round(self) -> F32x4 {
+        F32x4([
+            self[0].round_ties_even(),
+            self[1].round_ties_even(),
+            self[2].round_ties_even(),
+            self[3].round_ties_even(),
+        ])
+    }
+
+    /// Returns the square root of each lane. Negative lanes become NaN, and NaN lanes pass
+    /// through.
     #[inline]
     pub fn sqrt(self) -> F32x4 {
         F32x4([
@@ -315,6 +405,12 @@ impl F32x4 {
         ])
     }
 
+    /// Packs the sign bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        self.to_i32x4_bits().to_u32x4().movemask()
+    }
+
     /// Converts these packed floats to integers via rounding.
     #[inline]
     pub fn to_i32x4(self) -> I32x4 {
@@ -326,6 +422,37 @@ impl F32x4 {
         ])
     }
 
+    /// Converts these packed floats to integers by truncating toward zero, with the same
+    /// saturating semantics as `as i32`: out-of-range lanes clamp to `i32::MIN` or `i32::MAX`, and
+    /// NaN lanes become 0.
+    #[inline]
+    pub fn to_i32x4_saturating(self) -> I32x4 {
+        I32x4([self[0] as i32, self[1] as i32, self[2] as i32, self[3] as i32])
+    }
+
+    /// Reinterprets the bits of these packed floats as integers.
+    #[inline]
+    pub fn to_i32x4_bits(self) -> I32x4 {
+        I32x4([
+            self[0].to_bits() as i32,
+            self[1].to_bits() as i32,
+            self[2].to_bits() as i32,
+            self[3].to_bits() as i32,
+        ])
+    }
+
+    // Swizzles
+
+    /// Permutes the lanes as `_mm_shuffle_ps` does: lane `i` of the result is lane
+    /// `(IMM8 >> (2 * i)) & 3` of this vector, so `shuffle::<0b00_01_10_11>()` is `wzyx()`.
+    /// `IMM8` must be in `0..256`; other values fail to compile.
+    #[inline]
+    pub fn shuffle<const IMM8: i32>(self) -> F32x4 {
+        const { assert!(IMM8 >= 0 && IMM8 < 256, "shuffle index out of range") };
+        let lane = |i: i32| self[((IMM8 >> (2 * i)) & 3) as usize];
+        F32x4([lane(0), lane(1), lane(2), lane(3)])
+    }
+
     // Swizzle conversions
 
     #[inline]
@@ -584,6 +711,7 @@ impl Mul<I32x2> for I32x2 {
 // Four 32-bit signed integers
 
 #[derive(Clone, Copy, Default, Debug, PartialEq)]
+#[repr(transparent)]
 pub struct I32x4([i32; 4]);
 
 impl I32x4 {
@@ -597,6 +725,40 @@ impl I32x4 {
         I32x4([x; 4])
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [i32; 4]) -> I32x4 {
+        I32x4(array)
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 4] {
+        self.0
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const i32) -> I32x4 {
+        I32x4(*(ptr as *const [i32; 4]))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut i32) {
+        *(ptr as *mut [i32; 4]) = self.0
+    }
+
     // Basic operations
 
     #[inline]
@@ -619,6 +781,32 @@ impl I32x4 {
         ])
     }
 
+    /// Returns the absolute value of each lane. `i32::MIN` has no positive counterpart, so it
+    /// wraps around to itself, as with `i32::wrapping_abs`.
+    #[inline]
+    pub fn abs(self) -> I32x4 {
+        I32x4([
+            self[0].wrapping_abs(),
+            self[1].wrapping_abs(),
+            self[2].wrapping_abs(),
+            self[3].wrapping_abs(),
+        ])
+    }
+
+    /// Multiplies each pair of lanes and returns the high 32 bits of the 64-bit signed products,
+    /// as `((a as i64 * b as i64) >> 32) as i32` does. This scales each lane of `self` by the
+    /// fraction `other / 2³²`.
+    #[inline]
+    pub fn mulhi(self, other: I32x4) -> I32x4 {
+        let mulhi = |a: i32, b: i32| ((a as i64 * b as i64) >> 32) as i32;
+        I32x4([
+            mulhi(self[0], other[0]),
+            mulhi(self[1], other[1]),
+            mulhi(self[2], other[2]),
+            mulhi(self[3], other[3]),
+        ])
+    }
+
     // Packed comparisons
 
     #[inline]
@@ -661,6 +849,36 @@ impl I32x4 {
         ])
     }
 
+    #[inline]
+    pub fn packed_ge(self, other: I32x4) -> U32x4 {
+        U32x4([
+            if self[0] >= other[0] { !0 } else { 0 },
+            if self[1] >= other[1] { !0 } else { 0 },
+            if self[2] >= other[2] { !0 } else { 0 },
+            if self[3] >= other[3] { !0 } else { 0 },
+        ])
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> I32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+        I32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    /// Arithmetically shifts each lane right by the corresponding lane of `counts`, filling with
+    /// copies of the sign bit. Counts of 32 or more fill the whole lane with the sign bit.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> I32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i] >> counts[i].min(31);
+        I32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
     // Concatenations
 
     #[inline]
@@ -708,6 +926,17 @@ impl I32x4 {
         ])
     }
 
+    /// Reinterprets the bits of these packed integers as floats.
+    #[inline]
+    pub fn to_f32x4_bits(self) -> F32x4 {
+        F32x4([
+            f32::from_bits(self[0] as u32),
+            f32::from_bits(self[1] as u32),
+            f32::from_bits(self[2] as u32),
+            f32::from_bits(self[3] as u32),
+        ])
+    }
+
     /// Converts these packed signed integers to unsigned integers.
     ///
     /// Overflowing values will wrap around.
@@ -717,6 +946,22 @@ impl I32x4 {
     pub fn to_u32x4(self) -> U32x4 {
         U32x4([self[0] as u32, self[1] as u32, self[2] as u32, self[3] as u32])
     }
+
+    /// Clamps each lane to `0..=255` and packs the lanes into the bytes of a `u32`, lane 0 in the
+    /// least significant byte. Stored little-endian, the word's bytes are in RGBA order.
+    #[inline]
+    pub fn to_rgba8(self) -> u32 {
+        let byte = |i: usize| self[i].clamp(0, 255) as u32;
+        byte(0) | (byte(1) << 8) | (byte(2) << 16) | (byte(3) << 24)
+    }
+
+    /// Unpacks the bytes of a `u32` into the lanes, least significant byte in lane 0. This is the
+    /// inverse of `to_rgba8`.
+    #[inline]
+    pub fn from_u8_bytes(bytes: u32) -> I32x4 {
+        let [r, g, b, a] = bytes.to_le_bytes();
+        I32x4([r as i32, g as i32, b as i32, a as i32])
+    }
 }
 
 impl Index<usize> for I32x4 {
@@ -752,10 +997,10 @@ impl Sub<I32x4> for I32x4 {
     #[inline]
     fn sub(self, other: I32x4) -> I32x4 {
         I32x4([
-            self[0] - other[0],
-            self[1] - other[1],
-            self[2] - other[2],
-            self[3] - other[3],
+            self[0].wrapping_sub(other[0]),
+            self[1].wrapping_sub(other[1]),
+            self[2].wrapping_sub(other[2]),
+            self[3].wrapping_sub(other[3]),
         ])
     }
 }
@@ -773,6 +1018,22 @@ impl Mul<I32x4> for I32x4 {
     }
 }
 
+// Division rounds toward zero, like `i32::wrapping_div`, and is exact over the whole `i32` range.
+// Dividing by zero produces `i32::MIN` rather than panicking.
+impl Div<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn div(self, other: I32x4) -> I32x4 {
+        let div = |a: i32, b: i32| if b == 0 { i32::MIN } else { a.wrapping_div(b) };
+        I32x4([
+            div(self[0], other[0]),
+            div(self[1], other[1]),
+            div(self[2], other[2]),
+            div(self[3], other[3]),
+        ])
+    }
+}
+
 impl BitAnd<I32x4> for I32x4 {
     type Output = I32x4;
     #[inline]
@@ -789,6 +1050,42 @@ impl BitOr<I32x4> for I32x4 {
     }
 }
 
+impl BitXor<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn bitxor(self, other: I32x4) -> I32x4 {
+        I32x4([self[0] ^ other[0], self[1] ^ other[1], self[2] ^ other[2], self[3] ^ other[3]])
+    }
+}
+
+impl Not for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn not(self) -> I32x4 {
+        I32x4([!self[0], !self[1], !self[2], !self[3]])
+    }
+}
+
+impl Shl<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> I32x4 {
+        // Shifting by the lane width or more yields zero, as on x86, instead of overflowing.
+        let shift = |i: usize| self[i].checked_shl(amount).unwrap_or(0);
+        I32x4([shift(0), shift(1), shift(2), shift(3)])
+    }
+}
+
+impl Shr<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shr(self, amount: u32) -> I32x4 {
+        // Shifting by the lane width or more fills the lane with the sign bit, as on x86.
+        let amount = amount.min(31);
+        I32x4([self[0] >> amount, self[1] >> amount, self[2] >> amount, self[3] >> amount])
+    }
+}
+
 impl Shr<I32x4> for I32x4 {
     type Output = I32x4;
     #[inline]
@@ -878,6 +1175,7 @@ impl Index<usize> for U32x2 {
 // Four 32-bit unsigned integers
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(transparent)]
 pub struct U32x4(pub [u32; 4]);
 
 impl U32x4 {
@@ -885,6 +1183,45 @@ impl U32x4 {
         U32x4([a, b, c, d])
     }
 
+    #[inline]
+    pub fn splat(x: u32) -> U32x4 {
+        U32x4([x; 4])
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u32; 4]) -> U32x4 {
+        U32x4(array)
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 4] {
+        self.0
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const u32) -> U32x4 {
+        U32x4(*(ptr as *const [u32; 4]))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut u32) {
+        *(ptr as *mut [u32; 4]) = self.0
+    }
+
     // Conversions
 
     /// Converts these packed unsigned integers to signed integers.
@@ -916,6 +1253,60 @@ impl U32x4 {
     pub fn all_false(&self) -> bool {
         self[0] == 0 && self[1] == 0 && self[2] == 0 && self[3] == 0
     }
+
+    /// Packs the high bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        (self[0] >> 31) | ((self[1] >> 31) << 1) | ((self[2] >> 31) << 2) | ((self[3] >> 31) << 3)
+    }
+
+    // Selection
+
+    /// Picks each lane from `a` where this mask is true and from `b` where it is false.
+    ///
+    /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+        F32x4([
+            if self[0] != 0 { a[0] } else { b[0] },
+            if self[1] != 0 { a[1] } else { b[1] },
+            if self[2] != 0 { a[2] } else { b[2] },
+            if self[3] != 0 { a[3] } else { b[3] },
+        ])
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> U32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+        U32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    /// Logically shifts each lane right by the corresponding lane of `counts`. Counts of 32 or
+    /// more produce zero.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> U32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shr(counts[i]).unwrap_or(0);
+        U32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U32x4) -> U32x4 {
+        U32x4([
+            if self[0] == other[0] { !0 } else { 0 },
+            if self[1] == other[1] { !0 } else { 0 },
+            if self[2] == other[2] { !0 } else { 0 },
+            if self[3] == other[3] { !0 } else { 0 },
+        ])
+    }
 }
 
 impl Index<usize> for U32x4 {
@@ -926,10 +1317,344 @@ impl Index<usize> for U32x4 {
     }
 }
 
+impl IndexMut<usize> for U32x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u32 {
+        &mut self.0[index]
+    }
+}
+
+// Unsigned arithmetic wraps on overflow, like the SIMD backends.
+impl Add<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn add(self, other: U32x4) -> U32x4 {
+        U32x4([
+            self[0].wrapping_add(other[0]),
+            self[1].wrapping_add(other[1]),
+            self[2].wrapping_add(other[2]),
+            self[3].wrapping_add(other[3]),
+        ])
+    }
+}
+
+impl Sub<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn sub(self, other: U32x4) -> U32x4 {
+        U32x4([
+            self[0].wrapping_sub(other[0]),
+            self[1].wrapping_sub(other[1]),
+            self[2].wrapping_sub(other[2]),
+            self[3].wrapping_sub(other[3]),
+        ])
+    }
+}
+
+impl Mul<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn mul(self, other: U32x4) -> U32x4 {
+        U32x4([
+            self[0].wrapping_mul(other[0]),
+            self[1].wrapping_mul(other[1]),
+            self[2].wrapping_mul(other[2]),
+            self[3].wrapping_mul(other[3]),
+        ])
+    }
+}
+
+impl Not for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn not(self) -> U32x4 {
+        U32x4([!self[0], !self[1], !self[2], !self[3]])
+    }
+}
+
+impl BitAnd<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitand(self, other: U32x4) -> U32x4 {
+        U32x4([self[0] & other[0], self[1] & other[1], self[2] & other[2], self[3] & other[3]])
+    }
+}
+
+impl BitOr<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitor(self, other: U32x4) -> U32x4 {
+        U32x4([self[0] | other[0], self[1] | other[1], self[2] | other[2], self[3] | other[3]])
+    }
+}
+
+impl BitXor<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitxor(self, other: U32x4) -> U32x4 {
+        U32x4([self[0] ^ other[0], self[1] ^ other[1], self[2] ^ other[2], self[3] ^ other[3]])
+    }
+}
+
+impl Shl<u32> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> U32x4 {
+        // Shifting by the lane width or more yields zero, as on x86, instead of overflowing.
+        let shift = |i: usize| self[i].checked_shl(amount).unwrap_or(0);
+        U32x4([shift(0), shift(1), shift(2), shift(3)])
+    }
+}
+
 impl Shr<u32> for U32x4 {
     type Output = U32x4;
     #[inline]
     fn shr(self, amount: u32) -> U32x4 {
-        U32x4([self[0] >> amount, self[1] >> amount, self[2] >> amount, self[3] >> amount])
+        // Shifting by the lane width or more yields zero, as on x86, instead of overflowing.
+        let shift = |i: usize| self[i].checked_shr(amount).unwrap_or(0);
+        U32x4([shift(0), shift(1), shift(2), shift(3)])
+    }
+}
+
+// Sixteen 8-bit unsigned integers
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct U8x16(pub [u8; 16]);
+
+impl U8x16 {
+    // Constructors
+
+    #[inline]
+    pub fn splat(x: u8) -> U8x16 {
+        U8x16([x; 16])
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u8; 16]) -> U8x16 {
+        U8x16(array)
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u8; 16] {
+        self.0
+    }
+
+    // Conversions
+
+    /// Reinterprets these bytes as four packed 32-bit signed integers.
+    #[inline]
+    pub fn as_i32x4(self) -> I32x4 {
+        let mut lanes = [0; 4];
+        for (lane, bytes) in lanes.iter_mut().zip(self.0.chunks_exact(4)) {
+            *lane = i32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        I32x4(lanes)
+    }
+
+    /// Clamps each lane of `a`, `b`, `c`, and `d` to `0..=255` and packs them, in that order, into
+    /// the sixteen bytes: `a[0]` lands in byte 0 and `d[3]` in byte 15. This is `I32x4::to_rgba8`
+    /// for four vectors at once.
+    #[inline]
+    pub fn pack_i32x4s(a: I32x4, b: I32x4, c: I32x4, d: I32x4) -> U8x16 {
+        let mut result = [0; 16];
+        let lanes = a.0.iter().chain(&b.0).chain(&c.0).chain(&d.0);
+        for (byte, &lane) in result.iter_mut().zip(lanes) {
+            *byte = lane.clamp(0, 255) as u8;
+        }
+        U8x16(result)
+    }
+
+    // Basic operations
+
+    /// Returns a vector whose byte `i` is byte `indices[i] & 15` of this vector, or zero if the
+    /// high bit of `indices[i]` is set.
+    #[inline]
+    pub fn shuffle(self, indices: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (byte, &index) in result.iter_mut().zip(indices.0.iter()) {
+            if index & 0x80 == 0 {
+                *byte = self.0[(index & 15) as usize];
+            }
+        }
+        U8x16(result)
+    }
+
+    /// Packs the high bit of each byte into the low sixteen bits of an integer, byte 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        self.0.iter().enumerate().fold(0, |mask, (i, &byte)| mask | ((byte as u32 >> 7) << i))
+    }
+
+    /// Averages each pair of bytes, rounding halves up: `(a + b + 1) >> 1`.
+    #[inline]
+    pub fn avg(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = ((self.0[i] as u32 + other.0[i] as u32 + 1) >> 1) as u8;
+        }
+        U8x16(result)
+    }
+
+    #[inline]
+    pub fn min(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i].min(other.0[i]);
+        }
+        U8x16(result)
+    }
+
+    #[inline]
+    pub fn max(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i].max(other.0[i]);
+        }
+        U8x16(result)
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            if self.0[i] == other.0[i] {
+                *byte = !0;
+            }
+        }
+        U8x16(result)
+    }
+
+    // Filtering
+
+    /// Bilinearly interpolates, byte by byte, between the texels `tl`, `tr`, `bl`, and `br` at the
+    /// top left, top right, bottom left, and bottom right of a square. With RGBA8 data this
+    /// filters four pixels at once.
+    ///
+    /// The weights are fixed-point fractions with 8 fractional bits: 0 selects the left or top
+    /// texels, 256 selects the right or bottom ones, and 128 is halfway between. Weights above 256
+    /// are treated as 256. Each byte is interpolated horizontally and then vertically in 16-bit
+    /// lanes, rounding to nearest after each step.
+    #[inline]
+    pub fn bilinear_blend(tl: U8x16, tr: U8x16, bl: U8x16, br: U8x16, wx: u16, wy: u16)
+                          -> U8x16 {
+        let (wx, wy) = (wx.min(256), wy.min(256));
+        tl.lerp_fixed(tr, wx).lerp_fixed(bl.lerp_fixed(br, wx), wy)
+    }
+
+    // Computes `(self × (256 - weight) + other × weight + 128) >> 8` for each byte. `weight` must
+    // be at most 256.
+    #[inline]
+    fn lerp_fixed(self, other: U8x16, weight: u16) -> U8x16 {
+        let (self_weight, other_weight) = ((256 - weight) as u32, weight as u32);
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            let sum = self.0[i] as u32 * self_weight + other.0[i] as u32 * other_weight;
+            *byte = ((sum + 128) >> 8) as u8;
+        }
+        U8x16(result)
+    }
+}
+
+impl Debug for U8x16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let bytes = self.to_array();
+        write!(f, "<{}", bytes[0])?;
+        for byte in &bytes[1..] {
+            write!(f, ", {}", byte)?;
+        }
+        write!(f, ">")
+    }
+}
+
+impl Index<usize> for U8x16 {
+    type Output = u8;
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for U8x16 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        &mut self.0[index]
+    }
+}
+
+// Byte arithmetic saturates instead of wrapping, like the SIMD backends.
+impl Add<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn add(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i].saturating_add(other.0[i]);
+        }
+        U8x16(result)
+    }
+}
+
+impl Sub<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn sub(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i].saturating_sub(other.0[i]);
+        }
+        U8x16(result)
+    }
+}
+
+impl Not for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn not(self) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = !self.0[i];
+        }
+        U8x16(result)
+    }
+}
+
+impl BitAnd<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitand(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i] & other.0[i];
+        }
+        U8x16(result)
+    }
+}
+
+impl BitOr<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitor(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i] | other.0[i];
+        }
+        U8x16(result)
+    }
+}
+
+impl BitXor<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitxor(self, other: U8x16) -> U8x16 {
+        let mut result = [0; 16];
+        for (i, byte) in result.iter_mut().enumerate() {
+            *byte = self.0[i] ^ other.0[i];
+        }
+        U8x16(result)
     }
 }
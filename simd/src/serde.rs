@@ -0,0 +1,56 @@
+// pathfinder/simd/src/serde.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `serde` support. Vectors serialize as arrays of their four lanes, in order.
+//!
+//! Finite floats round-trip exactly through formats like JSON that print them in decimal, but NaN
+//! payloads are not preserved, and JSON cannot represent NaNs or infinities at all.
+
+use crate::default::{F32x4, I32x4, U32x4};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for F32x4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        self.to_array().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for F32x4 {
+    fn deserialize<D>(deserializer: D) -> Result<F32x4, D::Error> where D: Deserializer<'de> {
+        let [a, b, c, d] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(F32x4::new(a, b, c, d))
+    }
+}
+
+impl Serialize for I32x4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        self.to_array().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for I32x4 {
+    fn deserialize<D>(deserializer: D) -> Result<I32x4, D::Error> where D: Deserializer<'de> {
+        let [a, b, c, d] = <[i32; 4]>::deserialize(deserializer)?;
+        Ok(I32x4::new(a, b, c, d))
+    }
+}
+
+impl Serialize for U32x4 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        self.to_array().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for U32x4 {
+    fn deserialize<D>(deserializer: D) -> Result<U32x4, D::Error> where D: Deserializer<'de> {
+        let [a, b, c, d] = <[u32; 4]>::deserialize(deserializer)?;
+        Ok(U32x4::new(a, b, c, d))
+    }
+}
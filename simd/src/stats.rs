@@ -0,0 +1,68 @@
+// pathfinder/simd/src/stats.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Running statistics over streams of packed samples.
+
+use crate::default::F32x4;
+
+/// The running mean and variance of a stream of samples, four at a time.
+///
+/// Each lane keeps its own Welford accumulator, which stays accurate even when the mean is large
+/// compared to the spread of the samples. The lanes are merged only when `mean` or `variance` is
+/// called.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct F32Stats {
+    // The number of updates, which is the number of samples seen by each lane.
+    count: u32,
+    mean: F32x4,
+    // The sum of squared differences from the mean, per lane.
+    m2: F32x4,
+}
+
+impl F32Stats {
+    #[inline]
+    pub fn new() -> F32Stats {
+        F32Stats::default()
+    }
+
+    /// Adds four samples, one per lane.
+    #[inline]
+    pub fn update(&mut self, x: F32x4) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / F32x4::splat(self.count as f32);
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Returns the total number of samples, which is four per update.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.count * 4
+    }
+
+    /// Returns the mean of all samples, or zero if there are none.
+    #[inline]
+    pub fn mean(&self) -> f32 {
+        self.mean.sum() * 0.25
+    }
+
+    /// Returns the population variance of all samples, or zero if there are none.
+    #[inline]
+    pub fn variance(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        // Every lane has seen the same number of samples, so merging them adds the spread of the
+        // lane means, weighted by that count, to the spread within each lane.
+        let deviation = self.mean - F32x4::splat(self.mean());
+        let m2 = self.m2.sum() + deviation.dot(deviation) * self.count as f32;
+        m2 / self.count() as f32
+    }
+}
@@ -8,8 +8,20 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::default::{F32x4, I32x4, U32x4};
+use crate::default::{F32x4, I32x4, U32x4, U8x16};
+use crate::detect;
 use crate::scalar::F32x4 as F32x4S;
+use crate::{accumulate_weighted, blend, convolve_symmetric, count_byte, deinterleave_rgba};
+use crate::{dot3_batch, downscale_2x_rgba, f32_to_u8, histogram_u8, interleave_rgba};
+use crate::{mask_to_indices, poly_eval, prefix_sum, reduce_max_lanes, reduce_min_lanes};
+use crate::rms_slice;
+use crate::{saxpy_strided, srgb_decode_u8, srgb_encode_f32, transpose, transpose_4x4};
+use crate::{F32Stats, SimdF32x4, SimdMask};
+
+#[cfg(all(feature = "avx", target_feature = "avx2"))]
+use crate::I32x8;
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+use crate::{F32x8, U32x8};
 
 // F32x4
 
@@ -37,7 +49,11 @@ fn test_f32x4_accessors_and_mutators() {
 fn test_f32x4_basic_ops() {
     let a = F32x4::new(1.0, 3.0, 5.0, 7.0);
     let b = F32x4::new(2.0, 2.0, 6.0, 6.0);
+    // The estimate is only exact to the bit on x86; other backends compute it differently.
+    #[cfg(all(not(feature = "pf-no-simd"), any(target_arch = "x86", target_arch = "x86_64")))]
     assert_eq!(a.approx_recip(), F32x4::new(0.99975586, 0.33325195, 0.19995117, 0.14282227));
+    let recip_error = (a.approx_recip() * a - F32x4::splat(1.0)).abs();
+    assert!(recip_error.packed_gt(F32x4::splat(1.5 / 4096.0)).all_false());
     assert_eq!(a.min(b), F32x4::new(1.0, 2.0, 5.0, 6.0));
     assert_eq!(a.max(b), F32x4::new(2.0, 3.0, 6.0, 7.0));
     let c = F32x4::new(-1.0, 1.3, -20.0, 3.6);
@@ -50,15 +66,739 @@ fn test_f32x4_basic_ops() {
     assert_eq!(d.sqrt(), F32x4::new(1.0, 1.4142135, 1.7320508, 2.0));
 }
 
+#[test]
+fn test_f32x4_range_checks() {
+    let (lo, hi) = (F32x4::splat(-1.0), F32x4::splat(2.0));
+    let a = F32x4::new(-1.5, -1.0, 0.5, 2.0);
+    let b = F32x4::new(2.5, f32::NAN, f32::INFINITY, -0.0);
+    assert_eq!(a.in_closed_range(lo, hi), U32x4::new(0, !0, !0, !0));
+    assert_eq!(a.in_open_range(lo, hi), U32x4::new(0, 0, !0, 0));
+    assert_eq!(b.in_closed_range(lo, hi), U32x4::new(0, 0, 0, !0));
+    assert_eq!(b.in_open_range(lo, hi), U32x4::new(0, 0, 0, !0));
+    // Per-lane bounds.
+    let (lo, hi) = (F32x4::new(0.0, 1.0, 2.0, 3.0), F32x4::new(0.0, 2.0, 2.5, 3.5));
+    assert_eq!(F32x4::splat(2.0).in_closed_range(lo, hi), U32x4::new(0, !0, !0, 0));
+    assert_eq!(F32x4::splat(2.0).in_open_range(lo, hi), U32x4::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_f32x4_round() {
+    // Ties round to the even integer, not away from zero.
+    assert_eq!(F32x4::new(2.5, 3.5, -2.5, 0.5).round(), F32x4::new(2.0, 4.0, -2.0, 0.0));
+    assert_eq!(F32x4::new(-1.0, 1.3, -20.6, 3.6).round(), F32x4::new(-1.0, 1.0, -21.0, 4.0));
+}
+
+#[test]
+fn test_f32x4_map() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    assert!(a.map(f32::sqrt).approx_eq(a.sqrt(), 1e-6));
+    let b = F32x4::new(-2.0, 5.0, 1.0, 0.5);
+    assert_eq!(a.map2(b, f32::max), a.max(b));
+    let mut calls = 0;
+    assert_eq!(a.map(|x| { calls += 1; x * 2.0 }), a * F32x4::splat(2.0));
+    assert_eq!(calls, 4);
+}
+
 #[test]
 fn test_f32x4_packed_comparisons() {
     let a = F32x4::new(7.0, 3.0, 6.0, -2.0);
     let b = F32x4::new(10.0, 3.0, 5.0, -2.0);
     assert_eq!(a.packed_eq(b), U32x4::new(0, !0, 0, !0));
     assert_eq!(a.packed_gt(b), U32x4::new(0, 0, !0, 0));
+    assert_eq!(a.packed_lt(b), U32x4::new(!0, 0, 0, 0));
     assert_eq!(a.packed_le(b), U32x4::new(!0, !0, 0, !0));
 }
 
+// The WebAssembly backend emulates the NaN handling of `minps` and `maxps`, so that `clamp`
+// behaves as it does on x86.
+#[cfg(all(not(feature = "pf-no-simd"), target_arch = "wasm32", target_feature = "simd128"))]
+#[test]
+fn test_f32x4_min_max_nan_like_x86() {
+    let nan = F32x4::new(f32::NAN, 1.0, f32::NAN, 2.0);
+    let other = F32x4::new(1.0, f32::NAN, -1.0, 3.0);
+    assert_eq!(nan.min(other).to_array()[0], 1.0);
+    assert!(nan.min(other).to_array()[1].is_nan());
+    assert_eq!(nan.max(other).to_array()[2], -1.0);
+    assert_eq!(nan.max(other).to_array()[3], 3.0);
+    let clamped = nan.clamp(F32x4::splat(-5.0), F32x4::splat(5.0));
+    assert_eq!(clamped, F32x4::new(-5.0, 1.0, -5.0, 2.0));
+}
+
+#[test]
+fn test_f32x4_easing() {
+    let t = F32x4::new(0.0, 0.25, 0.5, 1.0);
+    assert!(t.ease_in_out_cubic().approx_eq(F32x4::new(0.0, 0.0625, 0.5, 1.0), 1e-6));
+    assert!(t.ease_out_quad().approx_eq(F32x4::new(0.0, 0.4375, 0.75, 1.0), 1e-6));
+    let outside = F32x4::new(-1.0, 2.0, 0.75, 0.0).ease_in_out_cubic();
+    assert!(outside.approx_eq(F32x4::new(0.0, 1.0, 0.9375, 0.0), 1e-6));
+
+    let (edge0, edge1) = (F32x4::splat(1.0), F32x4::splat(3.0));
+    let x = F32x4::new(1.0, 2.0, 3.0, 5.0);
+    assert_eq!(x.smoothstep_deriv(edge0, edge1), F32x4::new(0.0, 0.75, 0.0, 0.0));
+    let h = 1e-3;
+    let numeric = (F32x4::splat(1.5 + h).smoothstep(edge0, edge1) -
+                   F32x4::splat(1.5 - h).smoothstep(edge0, edge1)) * F32x4::splat(0.5 / h);
+    assert!(F32x4::splat(1.5).smoothstep_deriv(edge0, edge1).approx_eq(numeric, 1e-3));
+}
+
+#[test]
+fn test_f32x4_recip_and_rsqrt() {
+    let tolerance = F32x4::splat(1e-3);
+    for &scale in &[1.0e-6, 0.01, 1.0, 3.7, 250.0, 1.0e6] {
+        let a = F32x4::new(1.0, 1.5, 2.25, 7.0) * F32x4::splat(scale);
+        let recip = F32x4::splat(1.0) / a;
+        assert!(((a.recip() - recip) / recip).abs().packed_le(tolerance).all_true());
+        let rsqrt = F32x4::splat(1.0) / a.sqrt();
+        assert!(((a.rsqrt() - rsqrt) / rsqrt).abs().packed_le(tolerance).all_true());
+    }
+}
+
+#[test]
+fn test_f32x4_div_by_reciprocal() {
+    let divisor = 7.0;
+    let recip = F32x4::splat(1.0 / divisor);
+    for i in 0..16 {
+        let a = F32x4::new(i as f32, -3.5 * i as f32, 1.0e6 + i as f32, 1.0 / (i + 1) as f32);
+        let exact = a / F32x4::splat(divisor);
+        let tolerance = exact.abs() * F32x4::splat(f32::EPSILON * 2.0);
+        let error = (a.div_by_reciprocal(recip) - exact).abs();
+        assert!(error.packed_le(tolerance).all_true());
+    }
+}
+
+#[test]
+fn test_f32x4_safe_div() {
+    let a = F32x4::new(6.0, -1.0, 5.0, 0.0);
+    let divisor = F32x4::new(3.0, 0.0, -0.0, 4.0);
+    let (quotient, by_zero) = a.safe_div(divisor, F32x4::splat(-99.0));
+    assert_eq!(quotient, F32x4::new(2.0, -99.0, -99.0, 0.0));
+    assert_eq!(by_zero, U32x4::new(0, !0, !0, 0));
+
+    let (quotient, by_zero) = a.safe_div(F32x4::splat(0.5), F32x4::splat(f32::NAN));
+    assert_eq!(quotient, a * F32x4::splat(2.0));
+    assert!(by_zero.all_false());
+}
+
+#[test]
+fn test_f32x4_radix_key() {
+    let sorted = [
+        f32::NEG_INFINITY, f32::MIN, -2.0, -1.0, -1.0e-30, -1.0e-45, -0.0, 0.0, 1.0e-45, 0.5, 1.0,
+        7.25, 1.0e30, f32::INFINITY,
+    ];
+    let mut keys = Vec::new();
+    for chunk in sorted.chunks(2) {
+        let key = F32x4::new(chunk[0], chunk[1], 0.0, 0.0).radix_key();
+        keys.extend_from_slice(&[key[0], key[1]]);
+    }
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let nans = F32x4::new(f32::NAN, -f32::NAN, 0.0, 0.0).radix_key();
+    assert!(nans[0] > F32x4::splat(f32::INFINITY).radix_key()[0]);
+    assert!(nans[1] < F32x4::splat(f32::NEG_INFINITY).radix_key()[0]);
+}
+
+#[test]
+fn test_f32x4_lerp_endpoints() {
+    // With these values `a + (b - a) * t` rounds away from `b` at `t = 1`.
+    let a = F32x4::new(1.0e8, 0.1, -3.3, 7.0);
+    let b = F32x4::new(1.0, 0.7, 1.0e-7, -2.9);
+    assert_eq!(a.lerp(b, F32x4::splat(0.0)), a);
+    assert_eq!(a.lerp(b, F32x4::splat(1.0)), b);
+    assert_eq!(a.lerp(b, F32x4::new(0.0, 1.0, 0.0, 1.0)), F32x4::new(1.0e8, 0.7, -3.3, -2.9));
+}
+
+#[cfg(all(not(feature = "pf-no-simd"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[test]
+fn test_f32x4_floor_sse2() {
+    let special = F32x4::new(-0.0, f32::INFINITY, f32::NEG_INFINITY, 8388607.5);
+    assert_eq!(special.floor_sse2().to_i32x4_bits(), special.floor().to_i32x4_bits());
+    assert!(F32x4::splat(f32::NAN).floor_sse2()[0].is_nan());
+    let large = F32x4::new(-8388608.0, 1.0e20, -3.0e9, 16777217.0);
+    assert_eq!(large.floor_sse2(), large.floor());
+    for i in -2000..2000 {
+        let x = F32x4::splat(i as f32 * 0.37) + F32x4::new(0.0, 0.5, -0.999, 1.0e-6);
+        assert_eq!(x.floor_sse2().to_i32x4_bits(), x.floor().to_i32x4_bits());
+    }
+}
+
+#[test]
+fn test_detect() {
+    if cfg!(target_feature = "sse4.1") {
+        assert!(detect::has_sse41());
+    }
+    if cfg!(target_feature = "avx2") {
+        assert!(detect::has_avx() && detect::has_avx2());
+    }
+    if !cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        assert!(!detect::has_sse41() && !detect::has_fma());
+    }
+}
+
+#[cfg(feature = "libm")]
+#[test]
+fn test_f32x4_libm_transcendentals() {
+    let bits = |x: F32x4| x.to_i32x4_bits();
+    let inputs = [-1.0, -0.9999, -0.7, -0.1, 0.0, 1.0e-4, 0.3, 0.5, 0.866, 0.99, 1.0, 0.123];
+    for chunk in inputs.chunks(4) {
+        let x = F32x4::new(chunk[0], chunk[1], chunk[2], chunk[3]);
+        assert_eq!(bits(x.acos()), bits(x.map(libm::acosf)));
+        assert_eq!(bits(x.asin()), bits(x.map(libm::asinf)));
+
+        let c = x.abs();
+        let decoded = c.map(|c| {
+            if c <= 0.04045 { c / 12.92 } else { libm::powf((c + 0.055) / 1.055, 2.4) }
+        });
+        assert_eq!(bits(c.srgb_to_linear()), bits(decoded));
+        let encoded = c.map(|c| {
+            if c <= 0.0031308 { c * 12.92 } else { 1.055 * libm::powf(c, 1.0 / 2.4) - 0.055 }
+        });
+        assert_eq!(bits(c.linear_to_srgb()), bits(encoded));
+    }
+}
+
+#[test]
+fn test_f32x4_acos_asin() {
+    let inputs = [-1.0, -0.9999, -0.7, -0.5, -0.1, 0.0, 1.0e-4, 0.3, 0.5, 0.866, 0.99, 1.0];
+    for chunk in inputs.chunks(4) {
+        let x = F32x4::new(chunk[0], chunk[1], chunk[2], chunk[3]);
+        let (acos, asin) = (x.acos(), x.asin());
+        for i in 0..4 {
+            assert!((acos[i] - chunk[i].acos()).abs() < 1e-6);
+            assert!((asin[i] - chunk[i].asin()).abs() < 1e-6);
+        }
+    }
+    // The polynomial is exact at the ends and the middle of the range, where `libm` is not.
+    #[cfg(not(feature = "libm"))]
+    assert_eq!(F32x4::new(-1.0, 0.0, 1.0, 0.5).acos(),
+               F32x4::new(std::f32::consts::PI, std::f32::consts::FRAC_PI_2, 0.0, 0.5f32.acos()));
+
+    // Out-of-range lanes are clamped instead of becoming NaN.
+    let stray = F32x4::new(1.0000001, -1.0000001, 2.0, -5.0);
+    assert_eq!(stray.acos(), F32x4::new(1.0, -1.0, 1.0, -1.0).acos());
+    assert_eq!(stray.asin(), F32x4::new(1.0, -1.0, 1.0, -1.0).asin());
+}
+
+#[test]
+fn test_f32x4_barycentric() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = F32x4::new(-6.0, 0.5, 9.0, 0.0);
+    let c = F32x4::new(3.0, -2.5, 0.0, 8.0);
+    // The w lane of the weights must not contribute.
+    assert_eq!(F32x4::barycentric(a, b, c, F32x4::new(1.0, 0.0, 0.0, 5.0)), a);
+    assert_eq!(F32x4::barycentric(a, b, c, F32x4::new(0.0, 1.0, 0.0, 5.0)), b);
+    assert_eq!(F32x4::barycentric(a, b, c, F32x4::new(0.0, 0.0, 1.0, 5.0)), c);
+    let third = 1.0 / 3.0;
+    let centroid = F32x4::barycentric(a, b, c, F32x4::new(third, third, third, 0.0));
+    let expected = F32x4::new(-2.0 / 3.0, 0.0, 4.0, 4.0);
+    assert!((centroid - expected).abs().max_lane() < 1e-6);
+}
+
+#[test]
+fn test_f32x4_glsl_aliases() {
+    let a = F32x4::new(-1.25, 0.5, 2.75, 9.0);
+    let b = F32x4::new(2.0, -4.0, 1.0, 0.5);
+    let t = F32x4::new(0.0, 0.25, 0.5, 1.0);
+    assert_eq!(a.fract(), F32x4::new(0.75, 0.5, 0.75, 0.0));
+    assert_eq!(a.rem_euclid(b), F32x4::new(0.75, 0.5, 0.75, 0.0));
+    assert_eq!(a.lerp(b, t), F32x4::new(-1.25, -0.625, 1.875, 0.5));
+    assert_eq!(a.step(F32x4::splat(0.5)), F32x4::new(0.0, 1.0, 1.0, 1.0));
+    assert_eq!(t.smoothstep(F32x4::splat(0.0), F32x4::splat(1.0)),
+               F32x4::new(0.0, 0.15625, 0.5, 1.0));
+
+    assert_eq!(a.mix(b, t), a.lerp(b, t));
+    assert_eq!(a.r#mod(b), a.rem_euclid(b));
+    assert_eq!(b.abs().inversesqrt(), b.abs().approx_rsqrt());
+    assert_eq!(a.fma(b, t), a.mul_add(b, t));
+}
+
+#[test]
+fn test_f32x4_division() {
+    let mut a = F32x4::new(1.0, -6.0, 0.0, 9.0);
+    a /= F32x4::new(4.0, 3.0, 2.0, -3.0);
+    assert_eq!(a, F32x4::new(0.25, -2.0, 0.0, -3.0));
+    let b = F32x4::new(1.0, -1.0, 1.0, 0.0) / F32x4::new(0.0, 0.0, -0.0, 0.0);
+    assert_eq!((b.x(), b.y(), b.z()), (f32::INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY));
+    assert!(b.w().is_nan());
+}
+
+#[test]
+fn test_f32x4_abs() {
+    let nan = f32::from_bits(0xffc0_1234);
+    let a = F32x4::new(-0.0, -2.5, 3.0, nan).abs();
+    assert_eq!(a.to_i32x4_bits(), I32x4::new(0, 0x4020_0000, 0x4040_0000, 0x7fc0_1234));
+}
+
+#[test]
+fn test_f32x4_sqrt() {
+    assert_eq!(F32x4::new(1.0, 4.0, 9.0, 16.0).sqrt(), F32x4::new(1.0, 2.0, 3.0, 4.0));
+    let a = F32x4::new(-1.0, f32::NAN, 0.0, f32::INFINITY).sqrt();
+    assert!(a.x().is_nan() && a.y().is_nan());
+    assert_eq!((a.z(), a.w()), (0.0, f32::INFINITY));
+}
+
+#[test]
+fn test_f32x4_frexp_ldexp() {
+    let subnormal = f32::from_bits(1);
+    let a = F32x4::new(8.0, -3.0, 0.1, subnormal);
+    let (fraction, exponent) = a.frexp();
+    assert_eq!(fraction, F32x4::new(0.5, -0.75, 0.8, 0.5));
+    assert_eq!(exponent, I32x4::new(4, 2, -3, -148));
+    assert_eq!(fraction.ldexp(exponent), a);
+
+    let b = F32x4::new(0.0, -0.0, f32::INFINITY, f32::NAN);
+    let (fraction, exponent) = b.frexp();
+    assert_eq!(fraction.to_i32x4_bits(), b.to_i32x4_bits());
+    assert_eq!(exponent, I32x4::splat(0));
+
+    let c = F32x4::new(1.0, 1.0, 1.5, 3.0).ldexp(I32x4::new(128, -149, -130, -200));
+    assert_eq!(c, F32x4::new(f32::INFINITY, subnormal, f32::from_bits(0x000c_0000), 0.0));
+    let d = F32x4::splat(subnormal).ldexp(I32x4::splat(276));
+    assert_eq!(d, F32x4::splat(f32::from_bits(0x7f00_0000)));
+}
+
+#[test]
+fn test_f32x4_scale_pow2() {
+    let a = F32x4::new(1.5, -0.1, 3.0e10, -7.0);
+    assert_eq!(a.scale_pow2(I32x4::splat(3)), a * F32x4::splat(8.0));
+    assert_eq!(a.scale_pow2(I32x4::new(-1, 4, -20, 0)), a.ldexp(I32x4::new(-1, 4, -20, 0)));
+
+    let b = F32x4::new(1.0, -1.0, 1.0, 0.0).scale_pow2(I32x4::new(128, 200, -149, 5));
+    assert_eq!(b, F32x4::new(f32::INFINITY, f32::NEG_INFINITY, f32::from_bits(1), 0.0));
+    let c = F32x4::new(f32::MIN_POSITIVE, 1.0, 1.0, 1.0).scale_pow2(I32x4::new(-2, 0, 0, -300));
+    assert_eq!(c, F32x4::new(f32::from_bits(0x0020_0000), 1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_f32x4_next_up_and_down() {
+    let tiny = f32::from_bits(1);
+    let a = F32x4::new(1.0, 0.0, -0.0, -tiny);
+    let up = a.next_up();
+    assert_eq!(up.to_i32x4_bits(), I32x4::new(0x3f80_0001, 1, 1, i32::MIN));
+    assert_eq!(up.x(), 1.0 + f32::EPSILON);
+    let down = a.next_down();
+    assert_eq!(down.to_i32x4_bits(),
+               I32x4::new(0x3f7f_ffff, 0x8000_0001u32 as i32, 0x8000_0001u32 as i32, -0x7fff_fffe));
+
+    let b = F32x4::new(f32::INFINITY, f32::NEG_INFINITY, f32::MAX, f32::NAN);
+    let up = b.next_up();
+    assert_eq!((up.x(), up.y(), up.z()), (f32::INFINITY, -f32::MAX, f32::INFINITY));
+    assert!(up.w().is_nan());
+    let down = b.next_down();
+    assert_eq!((down.x(), down.y()), (f32::MAX, f32::NEG_INFINITY));
+
+    let c = F32x4::new(-2.5, 3.0e-40, 1.0e30, -7.0e-39);
+    assert_eq!(c.next_up().next_down(), c);
+    assert_eq!(c.next_down().next_up(), c);
+}
+
+#[test]
+fn test_f32x4_eq_ulps() {
+    let one_up = f32::from_bits(1.0f32.to_bits() + 1);
+    let three_up = f32::from_bits(1.0f32.to_bits() + 3);
+    let a = F32x4::new(1.0, 1.0, 0.0, f32::NAN);
+    let b = F32x4::new(one_up, three_up, -0.0, f32::NAN);
+    assert_eq!(a.eq_ulps(b, 1), U32x4::new(!0, 0, !0, 0));
+    assert_eq!(a.eq_ulps(b, 3), U32x4::new(!0, !0, !0, 0));
+    let tiny = f32::from_bits(1);
+    let c = F32x4::new(tiny, -tiny, f32::MAX, -1.0);
+    let d = F32x4::new(-tiny, tiny, f32::INFINITY, 1.0);
+    assert_eq!(c.eq_ulps(d, 1), U32x4::new(0, 0, !0, 0));
+    assert_eq!(c.eq_ulps(d, 2), U32x4::new(!0, !0, !0, 0));
+    assert_eq!(c.eq_ulps(d, u32::MAX), U32x4::new(!0, !0, !0, !0));
+}
+
+#[test]
+fn test_f32x4_clamp() {
+    let (min, max) = (F32x4::splat(0.0), F32x4::splat(1.0));
+    let a = F32x4::new(-0.5, 0.25, 1.5, f32::NAN).clamp(min, max);
+    assert_eq!(a, F32x4::new(0.0, 0.25, 1.0, 0.0));
+}
+
+#[test]
+fn test_f32x4_clamp_stable() {
+    let a = F32x4::new(-3.0, 0.5, 9.0, f32::NAN);
+    let (min, max) = (F32x4::splat(-1.0), F32x4::new(1.0, 1.0, 1.0, 2.0));
+    assert_eq!(a.clamp_stable(min, max), F32x4::new(-1.0, 0.5, 1.0, -1.0));
+    let b = F32x4::new(f32::NAN, f32::NEG_INFINITY, f32::INFINITY, 0.0);
+    assert_eq!(b.clamp_stable(min, max), F32x4::new(-1.0, -1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_f32x4_mul_add() {
+    let a = F32x4::new(1.5, -2.0, 1.0e10, 0.1);
+    let b = F32x4::new(4.0, 0.25, 3.0e-10, 0.3);
+    let c = F32x4::new(-1.0, 7.0, 2.0, 0.05);
+    assert!(a.mul_add(b, c).approx_eq(a * b + c, 1e-6));
+
+    // (1 + 2⁻¹²)² - (1 + 2⁻¹¹) is exactly 2⁻²⁴, which only a fused multiply-add preserves.
+    let x = F32x4::splat(1.0 + 1.0 / 4096.0);
+    let residual = x.mul_add(x, F32x4::splat(-(1.0 + 1.0 / 2048.0)));
+    let exact = F32x4::splat(1.0 / 16_777_216.0);
+    assert!(residual == exact || residual == F32x4::default());
+    #[cfg(all(target_feature = "fma", not(feature = "pf-no-simd")))]
+    assert_eq!(residual, exact);
+}
+
+#[test]
+fn test_f32x4_fma_acc() {
+    let a: Vec<f32> = (0..16).map(|i| i as f32 * 0.5).collect();
+    let b: Vec<f32> = (0..16).map(|i| 3.0 - i as f32).collect();
+    let mut acc = F32x4::default();
+    for (a, b) in a.chunks(4).zip(b.chunks(4)) {
+        acc.fma_acc(F32x4::from_slice(a), F32x4::from_slice(b));
+    }
+    let expected: f32 = a.iter().zip(b.iter()).map(|(a, b)| a * b).sum();
+    assert_eq!(acc[0] + acc[1] + acc[2] + acc[3], expected);
+    assert_eq!(F32x4::splat(2.0).mul_add(F32x4::splat(3.0), F32x4::splat(1.0)), F32x4::splat(7.0));
+}
+
+#[test]
+fn test_f32x4_prefix_sums() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(a.prefix_sum(), F32x4::new(1.0, 3.0, 6.0, 10.0));
+    assert_eq!(a.exclusive_prefix_sum(), F32x4::new(0.0, 1.0, 3.0, 6.0));
+    let b = F32x4::new(-0.5, 8.0, 0.25, -2.0);
+    let mut running = 0.0;
+    for i in 0..4 {
+        assert_eq!(b.exclusive_prefix_sum()[i], running);
+        running += b[i];
+        assert_eq!(b.prefix_sum()[i], running);
+    }
+}
+
+#[test]
+fn test_f32x4_sum() {
+    assert_eq!(F32x4::new(1.0, 2.0, 3.0, 4.0).sum(), 10.0);
+    assert_eq!(F32x4::new(4.0, -3.0, 2.0, 1.0).sum(), 4.0);
+    assert_eq!(F32x4::new(1.0, 4.0, -3.0, 2.0).sum(), 4.0);
+    assert_eq!(F32x4::new(-3.0, 2.0, 1.0, 4.0).sum(), 4.0);
+}
+
+#[test]
+fn test_f32x4_lane_min_max() {
+    let a = F32x4::new(3.0, -7.5, 2.0, -1.0);
+    assert_eq!((a.min_lane(), a.max_lane()), (-7.5, 3.0));
+    let b = F32x4::new(-0.5, -2.0, -9.0, 4.0);
+    assert_eq!((b.min_lane(), b.max_lane()), (-9.0, 4.0));
+    assert_eq!((a.wzyx().min_lane(), a.wzyx().max_lane()), (-7.5, 3.0));
+
+    // NaN lanes never win.
+    let nan = F32x4::new(f32::NAN, 1.0, -2.0, f32::NAN);
+    assert_eq!((nan.min_lane(), nan.max_lane()), (-2.0, 1.0));
+    let all_nan = F32x4::splat(f32::NAN);
+    assert_eq!((all_nan.min_lane(), all_nan.max_lane()), (f32::INFINITY, f32::NEG_INFINITY));
+}
+
+#[test]
+fn test_f32x4_norms() {
+    let a = F32x4::new(3.0, -7.5, 2.0, -20.0);
+    assert_eq!(a.norm_inf3(), 7.5);
+    assert_eq!(a.norm_inf4(), 20.0);
+    assert_eq!(a.norm_l1_3(), 12.5);
+    let b = F32x4::new(-4.0, 1.0, -0.5, 2.0);
+    assert_eq!(b.norm_inf3(), 4.0);
+    assert_eq!(b.norm_inf4(), 4.0);
+    assert_eq!(b.norm_l1_3(), 5.5);
+}
+
+#[test]
+fn test_f32x4_permutations() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = F32x4::new(5.0, 6.0, 7.0, 8.0);
+    assert_eq!(a.permute(I32x4::new(3, 0, 0, 2)), F32x4::new(4.0, 1.0, 1.0, 3.0));
+    assert_eq!(a.permute2(b, I32x4::new(7, 0, 5, 2)), F32x4::new(8.0, 1.0, 6.0, 3.0));
+    assert_eq!(a.permute2(b, I32x4::new(4, 4, 6, 5)), F32x4::new(5.0, 5.0, 7.0, 6.0));
+    assert_eq!(a.permute2(b, I32x4::new(3, 2, 1, 0)), F32x4::new(4.0, 3.0, 2.0, 1.0));
+}
+
+#[test]
+fn test_f32x4_gather_stride() {
+    let floats: Vec<f32> = (0..40).map(|i| i as f32 * 0.5).collect();
+    let mut bytes = vec![0u8; 1 + floats.len() * 4];
+    for (chunk, float) in bytes[1..].chunks_exact_mut(4).zip(&floats) {
+        chunk.copy_from_slice(&float.to_ne_bytes());
+    }
+    let base = bytes[1..].as_ptr();
+    let indices = I32x4::new(0, 3, 1, 9);
+    for &stride in &[4, 8, 12, 16] {
+        let gathered = unsafe { F32x4::gather_stride(base, indices, stride) };
+        let lane = |i: usize| floats[indices[i] as usize * stride as usize / 4];
+        assert_eq!(gathered, F32x4::new(lane(0), lane(1), lane(2), lane(3)));
+    }
+    let by_byte = unsafe { F32x4::gather_stride(base, I32x4::new(36, 0, 4, 8), 1) };
+    assert_eq!(by_byte, F32x4::new(floats[9], floats[0], floats[1], floats[2]));
+    let by_pair = unsafe { F32x4::gather_stride(base.offset(8), I32x4::new(-4, 0, 2, 6), 2) };
+    assert_eq!(by_pair, F32x4::new(floats[0], floats[2], floats[3], floats[5]));
+}
+
+#[test]
+fn test_f32x4_dot() {
+    let a = F32x4::new(1.0, -2.0, 3.5, 4.0);
+    let b = F32x4::new(0.5, 3.0, -1.0, 2.0);
+    assert_eq!(a.dot(b), -1.0);
+    assert_eq!(a.dot(b), (a * b).sum());
+    let c = F32x4::new(0.1, 0.2, 0.3, 0.4);
+    assert!((c.dot(a) - (c * a).sum()).abs() < 1e-6);
+}
+
+#[test]
+fn test_f32x4_3d_vector_operations() {
+    let a = F32x4::new(3.0, -4.0, 5.0, 9.0);
+    assert_eq!(a.dot3(F32x4::new(2.0, 1.0, 1.0, 100.0)), 7.0);
+
+    let x_axis = F32x4::new(2.0, 0.0, 0.0, 1.0);
+    assert_eq!(a.project_onto3(x_axis), F32x4::new(3.0, 0.0, 0.0, 0.0));
+    assert_eq!(a.reject_from3(x_axis), F32x4::new(0.0, -4.0, 5.0, 9.0));
+
+    let b = F32x4::new(1.0, 2.0, -2.0, 0.0);
+    let projection = a.project_onto3(b);
+    let rejection = a.reject_from3(b);
+    assert!((projection + rejection).approx_eq(a, 1e-6));
+    assert!(rejection.dot3(b).abs() < 1e-5);
+
+    assert_eq!(a.project_onto3(F32x4::new(0.0, 0.0, 0.0, 1.0)), F32x4::default());
+    assert_eq!(a.reject_from3(F32x4::default()), a);
+}
+
+#[test]
+fn test_f32x4_4d_vector_operations() {
+    let a = F32x4::new(3.0, 4.0, 0.0, 0.0);
+    assert_eq!(a.dot4(F32x4::new(1.0, 2.0, 100.0, 100.0)), 11.0);
+    assert_eq!(a.rms(), 2.5);
+    assert_eq!(F32x4::new(1.0, -2.0, 3.0, -4.0).dot4(F32x4::splat(1.0)), -2.0);
+    assert_eq!(F32x4::splat(-3.0).rms(), 3.0);
+}
+
+#[test]
+fn test_f32x4_2d_vector_operations() {
+    let a = F32x4::new(3.0, -4.0, 7.0, 9.0);
+    assert_eq!(a.dot2(F32x4::new(2.0, 1.0, 100.0, 100.0)), 2.0);
+    assert_eq!(a.length2_sq(), 25.0);
+    assert_eq!(a.length2(), 5.0);
+    assert!(a.normalize2().approx_eq(F32x4::new(0.6, -0.8, 7.0, 9.0), 1e-6));
+
+    let b = F32x4::new(0.0, 1.0001, 2.0, 3.0);
+    assert!(b.normalize2().approx_eq(F32x4::new(0.0, 1.0, 2.0, 3.0), 1e-6));
+
+    let zero = F32x4::new(0.0, 0.0, 5.0, 6.0);
+    assert_eq!(zero.normalize2(), zero);
+
+    // Squared lengths that underflow, are subnormal, or overflow still normalize.
+    let tiny = F32x4::new(1e-20, 0.0, 7.0, 9.0);
+    assert!(tiny.normalize2().approx_eq(F32x4::new(1.0, 0.0, 7.0, 9.0), 1e-6));
+    let subnormal = F32x4::new(3e-23, -4e-23, 7.0, 9.0);
+    assert!(subnormal.length2_sq() < f32::MIN_POSITIVE);
+    assert!(subnormal.normalize2().approx_eq(F32x4::new(0.6, -0.8, 7.0, 9.0), 1e-6));
+    let huge = F32x4::new(1e20, 1e20, 7.0, 9.0);
+    let root_half = std::f32::consts::FRAC_1_SQRT_2;
+    assert!(huge.normalize2().approx_eq(F32x4::new(root_half, root_half, 7.0, 9.0), 1e-6));
+}
+
+#[test]
+fn test_f32x4_negate_if() {
+    let a = F32x4::new(1.5, -2.0, 0.0, -0.0);
+    let flipped = a.negate_if(U32x4::new(!0, !0, !0, !0));
+    assert_eq!(flipped.to_i32x4_bits(), F32x4::new(-1.5, 2.0, -0.0, 0.0).to_i32x4_bits());
+    let some = a.negate_if(U32x4::new(!0, 0, 0, !0));
+    assert_eq!(some.to_i32x4_bits(), F32x4::new(-1.5, -2.0, 0.0, 0.0).to_i32x4_bits());
+    assert_eq!(a.negate_if(U32x4::splat(0)).to_i32x4_bits(), a.to_i32x4_bits());
+}
+
+#[test]
+fn test_f32x4_signed_area2() {
+    let (a, b, c) = (F32x4::new(1.0, 1.0, 0.0, 0.0),
+                     F32x4::new(4.0, 1.0, 0.0, 0.0),
+                     F32x4::new(1.0, 5.0, 0.0, 0.0));
+    // Counterclockwise winding gives a positive area, clockwise a negative one.
+    assert_eq!((b - a).signed_area2(c - a), 12.0);
+    assert_eq!((c - a).signed_area2(b - a), -12.0);
+    assert_eq!(F32x4::new(2.0, 3.0, 9.0, 9.0).signed_area2(F32x4::new(4.0, 6.0, 1.0, 1.0)), 0.0);
+}
+
+#[test]
+fn test_f32x4_polar_coordinates() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    assert_eq!(F32x4::new(0.0, 0.0, 1.0, 2.0).to_polar2(), F32x4::new(0.0, 0.0, 1.0, 2.0));
+    assert_eq!(F32x4::new(-0.0, 0.0, 0.0, 0.0).to_polar2(), F32x4::default());
+    let up = F32x4::new(0.0, 2.0, 0.0, 0.0).to_polar2();
+    assert!(up.approx_eq(F32x4::new(2.0, FRAC_PI_2, 0.0, 0.0), 1e-6));
+    let left = F32x4::new(-3.0, 0.0, 0.0, 0.0).to_polar2();
+    assert!(left.approx_eq(F32x4::new(3.0, PI, 0.0, 0.0), 1e-6));
+    for &(x, y) in &[(1.0, 0.0), (0.0, -4.0), (3.0, 4.0), (-2.5, 0.75), (0.0, 0.0)] {
+        let point = F32x4::new(x, y, 5.0, 6.0);
+        assert!(point.to_polar2().from_polar2().approx_eq(point, 1e-5));
+    }
+}
+
+#[test]
+fn test_f32x4_rotation2() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    let quarter = F32x4::rotation2(FRAC_PI_2);
+    assert!(quarter.approx_eq(F32x4::new(0.0, -1.0, 1.0, 0.0), 1e-6));
+    let x_axis = F32x4::new(1.0, 0.0, 7.0, 8.0);
+    assert!(quarter.apply_rotation2(x_axis).approx_eq(F32x4::new(0.0, 1.0, 7.0, 8.0), 1e-6));
+    let half = F32x4::rotation2(PI);
+    let point = F32x4::new(3.0, -2.0, 0.0, 1.0);
+    assert!(half.apply_rotation2(point).approx_eq(F32x4::new(-3.0, 2.0, 0.0, 1.0), 1e-6));
+    assert_eq!(F32x4::rotation2(0.0).apply_rotation2(point), point);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck_casts() {
+    let vectors = [F32x4::new(1.0, 2.0, 3.0, 4.0), F32x4::new(5.0, 6.0, 7.0, 8.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&vectors);
+    assert_eq!(floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let bytes: &[u8] = bytemuck::cast_slice(&vectors[1..]);
+    assert_eq!(bytes[..4], 5.0f32.to_ne_bytes());
+    let integers = [I32x4::new(-1, 2, -3, 4)];
+    assert_eq!(bytemuck::cast_slice::<I32x4, i32>(&integers), [-1, 2, -3, 4]);
+    assert_eq!(bytemuck::cast::<U32x4, [u32; 4]>(U32x4::new(9, 8, 7, 6)), [9, 8, 7, 6]);
+    assert_eq!(<F32x4 as bytemuck::Zeroable>::zeroed(), F32x4::default());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let a = F32x4::new(0.1, -2.5e-38, 3.4028235e38, -0.0);
+    let json = serde_json::to_string(&a).unwrap();
+    let b: F32x4 = serde_json::from_str(&json).unwrap();
+    assert_eq!(b.to_i32x4_bits(), a.to_i32x4_bits());
+    let i = I32x4::new(i32::MIN, -1, 0, i32::MAX);
+    assert_eq!(serde_json::to_string(&i).unwrap(), "[-2147483648,-1,0,2147483647]");
+    assert_eq!(serde_json::from_str::<I32x4>("[-2147483648,-1,0,2147483647]").unwrap(), i);
+    let u = U32x4::new(0, 1, 2, !0);
+    assert_eq!(serde_json::from_str::<U32x4>(&serde_json::to_string(&u).unwrap()).unwrap(), u);
+    assert!(serde_json::from_str::<U32x4>("[1, 2, 3]").is_err());
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn test_f32x4_mint_conversions() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    let vector4: mint::Vector4<f32> = a.into();
+    assert_eq!(vector4, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 });
+    assert_eq!(F32x4::from(vector4), a);
+
+    let point3 = mint::Point3 { x: 5.0, y: 6.0, z: 7.0 };
+    assert_eq!(F32x4::from(point3), F32x4::new(5.0, 6.0, 7.0, 1.0));
+    assert_eq!(mint::Point3::from(a), mint::Point3 { x: 1.0, y: 2.0, z: 3.0 });
+    let vector3 = mint::Vector3 { x: 5.0, y: 6.0, z: 7.0 };
+    assert_eq!(F32x4::from(vector3), F32x4::new(5.0, 6.0, 7.0, 0.0));
+    assert_eq!(mint::Vector3::from(a), mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+}
+
+#[test]
+fn test_f32x4_loads_and_stores() {
+    #[repr(align(16))]
+    struct Aligned([f32; 4]);
+
+    let a = F32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(a.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    let mut aligned = Aligned([0.0; 4]);
+    unsafe {
+        a.store_aligned(aligned.0.as_mut_ptr());
+        assert_eq!(F32x4::load_aligned(aligned.0.as_ptr()), a);
+    }
+    assert_eq!(aligned.0, [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(F32x4::load(&[5.0, 6.0, 7.0, 8.0, 9.0]), F32x4::new(5.0, 6.0, 7.0, 8.0));
+    assert_eq!(F32x4::load_partial(&[5.0, 6.0]), F32x4::new(5.0, 6.0, 0.0, 0.0));
+    let mut slice = [0.0; 3];
+    a.store_partial(&mut slice);
+    assert_eq!(slice, [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_f32x4_array_conversions() {
+    assert_eq!(F32x4::from([1.0, 2.0, 3.0, 4.0]).to_array(), [1.0, 2.0, 3.0, 4.0]);
+    let array: [f32; 4] = F32x4::new(5.0, -6.0, 7.0, -8.0).into();
+    assert_eq!(array, [5.0, -6.0, 7.0, -8.0]);
+    // Round-trip through a deliberately misaligned buffer.
+    let mut buffer = [0.0f32; 5];
+    buffer[1..5].copy_from_slice(&F32x4::from(array).to_array());
+    assert_eq!(F32x4::load(&buffer[1..5]).to_array(), array);
+}
+
+#[test]
+fn test_array_conversions_and_iteration() {
+    let v: F32x4 = [1.5, -2.0, 3.25, 4.0].into();
+    let lanes: Vec<f32> = (&v).into_iter().collect();
+    assert_eq!(lanes, [1.5, -2.0, 3.25, 4.0]);
+    let mut sum = 0.0;
+    for x in &v {
+        sum += x;
+    }
+    assert_eq!(sum, 6.75);
+    let i: I32x4 = [1, -2, 3, i32::MIN].into();
+    assert_eq!(i, I32x4::new(1, -2, 3, i32::MIN));
+    assert_eq!(<[i32; 4]>::from(i), [1, -2, 3, i32::MIN]);
+    let u: U32x4 = [0, 1, 2, !0].into();
+    assert_eq!(u, U32x4::new(0, 1, 2, !0));
+    assert_eq!(<[u32; 4]>::from(u), [0, 1, 2, !0]);
+}
+
+#[test]
+fn test_f32x4_sum_and_product() {
+    let vectors = [F32x4::new(1.0, 2.0, 3.0, 4.0),
+                   F32x4::new(-0.5, 0.5, 2.0, 0.0),
+                   F32x4::new(10.0, 20.0, -4.0, 0.25)];
+    assert_eq!(vectors.iter().copied().sum::<F32x4>(), vectors[0] + vectors[1] + vectors[2]);
+    assert_eq!(vectors.iter().copied().product::<F32x4>(),
+               vectors[0] * vectors[1] * vectors[2]);
+    assert_eq!(std::iter::empty().sum::<F32x4>(), F32x4::default());
+    assert_eq!(std::iter::empty().product::<F32x4>(), F32x4::splat(1.0));
+}
+
+fn clamp01<T: SimdF32x4>(v: T) -> T {
+    v.max(T::splat(0.0)).min(T::splat(1.0))
+}
+
+fn count_negative<T: SimdF32x4>(v: T) -> u32 {
+    v.packed_lt(T::splat(0.0)).count_true()
+}
+
+#[test]
+fn test_simd_f32x4_generic() {
+    let a = F32x4::new(-0.5, 0.25, 1.5, 1.0);
+    assert_eq!(clamp01(a), F32x4::new(0.0, 0.25, 1.0, 1.0));
+    assert_eq!(count_negative(a), 1);
+    let mask = SimdF32x4::packed_gt(a, F32x4::splat(0.5));
+    assert_eq!(<F32x4 as SimdF32x4>::select(mask, a, -a), F32x4::new(0.5, -0.25, 1.5, 1.0));
+}
+
+#[test]
+fn test_f32x4_const_shuffle() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(a.shuffle::<0b00_01_10_11>(), a.wzyx());
+    assert_eq!(a.shuffle::<0b00_01_10_11>(), F32x4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(a.shuffle::<0b11_10_01_00>(), a);
+    assert_eq!(a.shuffle::<0b01_01_00_10>(), a.zxyy());
+    assert_eq!(a.shuffle::<0xff>(), a.wwww());
+}
+
+#[test]
+fn test_lane_getters() {
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = I32x4::new(-1, -2, -3, -4);
+    let c = U32x4::new(5, 6, 7, 8);
+    let d = U8x16::from_array([9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24]);
+    for i in 0..4 {
+        assert_eq!(a.get(i), Some(a[i]));
+        assert_eq!(b.get(i), Some(b[i]));
+        assert_eq!(c.get(i), Some(c[i]));
+        unsafe {
+            assert_eq!(Some(a.get_unchecked(i)), a.get(i));
+            assert_eq!(Some(b.get_unchecked(i)), b.get(i));
+            assert_eq!(Some(c.get_unchecked(i)), c.get(i));
+        }
+    }
+    for i in 0..16 {
+        assert_eq!(d.get(i), Some(i as u8 + 9));
+        assert_eq!(Some(unsafe { d.get_unchecked(i) }), d.get(i));
+    }
+    assert_eq!((a.get(4), b.get(4), c.get(usize::MAX), d.get(16)), (None, None, None, None));
+}
+
 #[test]
 fn test_f32x4_swizzles() {
     let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
@@ -372,6 +1112,22 @@ fn test_f32x4_conversions() {
     assert_eq!(a.to_i32x4(), I32x4::new(48, -4, 200, 7));
 }
 
+#[test]
+fn test_f32x4_to_i32x4_saturating() {
+    let special = F32x4::new(f32::INFINITY, f32::NEG_INFINITY, f32::NAN, 1e20);
+    assert_eq!(special.to_i32x4_saturating(), I32x4::new(i32::MAX, i32::MIN, 0, i32::MAX));
+    let edges = F32x4::new(2147483648.0, -2147483648.0, 2147483520.0, -1e20);
+    assert_eq!(edges.to_i32x4_saturating(),
+               I32x4::new(i32::MAX, i32::MIN, 2147483520, i32::MIN));
+    let a = F32x4::new(-2.7, -0.5, 0.9, 3.5);
+    assert_eq!(a.to_i32x4_saturating(), I32x4::new(-2, 0, 0, 3));
+    for lane in 0..4 {
+        for &x in &[special, edges, a] {
+            assert_eq!(x.to_i32x4_saturating()[lane], x[lane] as i32);
+        }
+    }
+}
+
 #[test]
 fn test_f32x4_debug() {
     let a = F32x4::new(48.0, -4.0, 200.0, 7.0);
@@ -393,6 +1149,119 @@ fn test_i32x4_basic_ops() {
     let a = I32x4::new(6, 29, -40, 2);
     let b = I32x4::new(10, -5, 10, 46);
     assert_eq!(a.min(b), I32x4::new(6, -5, -40, 2));
+    assert_eq!(a.max(b), I32x4::new(10, 29, 10, 46));
+    let (lo, hi) = (I32x4::splat(-10), I32x4::new(0, 20, 20, 20));
+    assert_eq!(a.clamp(lo, hi), I32x4::new(0, 20, -10, 2));
+}
+
+#[test]
+fn test_i32x4_bitwise_ops() {
+    let a = I32x4::new(0x0f0f_0f0f, -1, 0, 0x1234_5678);
+    let b = I32x4::new(0x00ff_00ff, 0x5555_5555, -1, -0x1234_5679);
+    for i in 0..4 {
+        assert_eq!((a & b)[i], a[i] & b[i]);
+        assert_eq!((a | b)[i], a[i] | b[i]);
+        assert_eq!((a ^ b)[i], a[i] ^ b[i]);
+        assert_eq!((!a)[i], !a[i]);
+    }
+    let (ones, zeros) = (I32x4::splat(-1), I32x4::default());
+    assert_eq!(!ones, zeros);
+    assert_eq!(!zeros, ones);
+    assert_eq!(a & ones, a);
+    assert_eq!(a & zeros, zeros);
+    assert_eq!(a | ones, ones);
+    assert_eq!(a | zeros, a);
+    assert_eq!(a ^ ones, !a);
+    assert_eq!(a ^ a, zeros);
+}
+
+#[test]
+fn test_i32x4_u32x4_shifts() {
+    let signed = I32x4::new(-8, 8, i32::MIN, -1);
+    assert_eq!(signed >> 1, I32x4::new(-4, 4, i32::MIN / 2, -1));
+    assert_eq!(signed >> 31, I32x4::new(-1, 0, -1, -1));
+    assert_eq!(signed >> 32, I32x4::new(-1, 0, -1, -1));
+    assert_eq!(signed << 1, I32x4::new(-16, 16, 0, -2));
+    assert_eq!(signed << 31, I32x4::new(0, 0, 0, i32::MIN));
+    assert_eq!(signed << 32, I32x4::splat(0));
+
+    let unsigned = signed.to_u32x4();
+    assert_eq!(unsigned >> 1, U32x4::new(0x7fff_fffc, 4, 0x4000_0000, 0x7fff_ffff));
+    assert_eq!(unsigned >> 31, U32x4::new(1, 0, 1, 1));
+    assert_eq!(unsigned >> 32, U32x4::splat(0));
+    assert_eq!(unsigned << 1, (signed << 1).to_u32x4());
+    assert_eq!(unsigned << 31, U32x4::new(0, 0, 0, 0x8000_0000));
+    assert_eq!(unsigned << 32, U32x4::splat(0));
+
+    let counts = U32x4::new(1, 31, 32, 0);
+    assert_eq!(signed.shl_each(counts), I32x4::new(-16, 0, 0, -1));
+    assert_eq!(signed.shr_each(counts), I32x4::new(-4, 0, -1, -1));
+    assert_eq!(I32x4::splat(i32::MAX).shr_each(U32x4::new(31, 32, 100, 30)),
+               I32x4::new(0, 0, 0, 1));
+}
+
+#[test]
+fn test_i32x4_eq_scalar() {
+    let sentinel = I32x4::splat(-1);
+    assert!(sentinel.all_eq_scalar(-1) && sentinel.any_eq_scalar(-1));
+    let one_valid = I32x4::new(-1, -1, 7, -1);
+    assert!(!one_valid.all_eq_scalar(-1) && one_valid.any_eq_scalar(-1));
+    assert!(one_valid.any_eq_scalar(7) && !one_valid.any_eq_scalar(0));
+    let uniform = I32x4::splat(12);
+    assert!(uniform.all_eq_scalar(12) && !uniform.all_eq_scalar(-1));
+    assert!(!uniform.any_eq_scalar(-1));
+
+    let sentinel = U32x4::splat(!0);
+    assert!(sentinel.all_eq_scalar(!0) && sentinel.any_eq_scalar(!0));
+    let one_valid = U32x4::new(!0, 3, !0, !0);
+    assert!(!one_valid.all_eq_scalar(!0) && one_valid.any_eq_scalar(!0));
+    assert!(one_valid.any_eq_scalar(3) && !U32x4::splat(3).any_eq_scalar(!0));
+}
+
+#[test]
+fn test_i32x4_abs_and_neg() {
+    let a = I32x4::new(-7, 0, 12, i32::MIN + 1);
+    assert_eq!(a.abs(), I32x4::new(7, 0, 12, i32::MAX));
+    assert_eq!(-a, I32x4::new(7, 0, -12, i32::MAX));
+    // `i32::MIN` has no positive counterpart and wraps around to itself.
+    let b = I32x4::new(i32::MIN, i32::MAX, -1, 1);
+    for i in 0..4 {
+        assert_eq!(b.abs()[i], b[i].wrapping_abs());
+        assert_eq!((-b)[i], b[i].wrapping_neg());
+    }
+}
+
+// Run with and without `-C target-feature=+sse4.1` to cover both x86 code paths.
+#[test]
+fn test_i32x4_mulhi() {
+    let values = [0, 1, -1, 7, -7, 0x1_0000, -0x1_0000, 0x7654_3210, -0x1234_5678, i32::MAX,
+                  i32::MIN, i32::MIN + 1];
+    for &a in &values {
+        for b in values.chunks_exact(4) {
+            let expected = b.iter().map(|&b| ((a as i64 * b as i64) >> 32) as i32);
+            let b = I32x4::from_array([b[0], b[1], b[2], b[3]]);
+            let actual = I32x4::splat(a).mulhi(b);
+            assert!(actual.to_array().iter().copied().eq(expected.clone()), "{} × {:?}", a, b);
+            assert!(b.mulhi(I32x4::splat(a)).to_array().iter().copied().eq(expected));
+        }
+    }
+    // 0x6000_0000 is 0.375 in 0.32 fixed point.
+    let scaled = I32x4::new(1000, -1000, 8, 0).mulhi(I32x4::splat(0x6000_0000));
+    assert_eq!(scaled, I32x4::new(375, -375, 3, 0));
+}
+
+#[test]
+fn test_i32x4_min_max_extremes() {
+    let values = [i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX - 1, i32::MAX];
+    for &x in &values {
+        let a = I32x4::splat(x);
+        let b = I32x4::from_array([values[0], values[2], values[3], values[6]]);
+        let (min, max) = (a.min(b), a.max(b));
+        for i in 0..4 {
+            assert_eq!(min[i], x.min(b[i]));
+            assert_eq!(max[i], x.max(b[i]));
+        }
+    }
 }
 
 #[test]
@@ -400,6 +1269,90 @@ fn test_i32x4_packed_comparisons() {
     let a = I32x4::new(59, 1, 5, 63);
     let b = I32x4::new(-59, 1, 5, 104);
     assert_eq!(a.packed_eq(b), U32x4::new(0, !0, !0, 0));
+
+    let c = I32x4::new(-5, 3, 7, i32::MIN);
+    let d = I32x4::new(-5, 4, 6, i32::MAX);
+    assert_eq!(c.packed_lt(d), U32x4::new(0, !0, 0, !0));
+    assert_eq!(c.packed_le(d), U32x4::new(!0, !0, 0, !0));
+    assert_eq!(c.packed_gt(d), U32x4::new(0, 0, !0, 0));
+    assert_eq!(c.packed_ge(d), U32x4::new(!0, 0, !0, 0));
+    assert_eq!(c.packed_ne(d), U32x4::new(0, !0, !0, !0));
+    assert_eq!(a.packed_ne(b), !a.packed_eq(b));
+
+    // At equality only the non-strict predicates hold.
+    let e = I32x4::new(0, -1, i32::MIN, i32::MAX);
+    assert!(e.packed_lt(e).all_false() && e.packed_gt(e).all_false());
+    assert!(e.packed_le(e).all_true() && e.packed_ge(e).all_true());
+    assert!(e.packed_ne(e).all_false());
+}
+
+#[test]
+fn test_i32x4_loads_and_stores() {
+    #[repr(align(16))]
+    struct Aligned([i32; 4]);
+
+    let a = I32x4::from_array([1, -2, 3, -4]);
+    assert_eq!(a.to_array(), [1, -2, 3, -4]);
+    let mut aligned = Aligned([0; 4]);
+    unsafe {
+        a.store_aligned(aligned.0.as_mut_ptr());
+        assert_eq!(I32x4::load_aligned(aligned.0.as_ptr()), a);
+    }
+    let mut slice = [0; 5];
+    a.store(&mut slice);
+    assert_eq!(slice, [1, -2, 3, -4, 0]);
+    assert_eq!(I32x4::load(&slice[1..]), I32x4::new(-2, 3, -4, 0));
+    assert_eq!(I32x4::load_partial(&[7]), I32x4::new(7, 0, 0, 0));
+    assert_eq!(I32x4::load_partial(&[]), I32x4::default());
+    a.store_partial(&mut slice[3..]);
+    assert_eq!(slice, [1, -2, 3, 1, -2]);
+}
+
+#[test]
+fn test_i32x4_f32x4_conversions() {
+    let a = I32x4::new(-3, 0, 1 << 20, 0x3f80_0000);
+    assert_eq!(a.to_f32x4(), F32x4::new(-3.0, 0.0, 1048576.0, 1065353216.0));
+    assert_eq!(a.to_f32x4().to_i32x4(), a);
+    let bits = a.to_f32x4_bits();
+    assert_eq!(bits.w(), 1.0);
+    assert_eq!(bits.x().to_bits(), -3i32 as u32);
+    assert_eq!(bits.to_i32x4_bits(), a);
+}
+
+#[test]
+fn test_i32x4_to_rgba8() {
+    assert_eq!(I32x4::new(0x12, 0x34, 0x56, 0x78).to_rgba8(), 0x7856_3412);
+    assert_eq!(I32x4::new(300, -5, 128, 255).to_rgba8(), 0xff80_00ff);
+    assert_eq!(I32x4::new(i32::MIN, i32::MAX, 0, 1).to_rgba8().to_le_bytes(), [0, 255, 0, 1]);
+}
+
+#[test]
+fn test_i32x4_division() {
+    let a = I32x4::new((1 << 24) + 1, -7, i32::MAX, i32::MIN);
+    let b = I32x4::new(1, 2, 7, -3);
+    let quotient = a / b;
+    for i in 0..4 {
+        assert_eq!(quotient[i], a[i] / b[i]);
+    }
+    let c = I32x4::new(i32::MIN, 5, -5, i32::MAX - 1);
+    let d = I32x4::new(-1, 0, -6, i32::MAX);
+    assert_eq!(c / d, I32x4::new(i32::MIN, i32::MIN, 0, 0));
+}
+
+#[test]
+fn test_i32x4_subtraction_overflow() {
+    let a = I32x4::new(i32::MIN, i32::MIN + 1, i32::MAX, -5);
+    let b = I32x4::new(1, 1, -1, 3);
+    assert_eq!(a - b, I32x4::new(i32::MAX, i32::MIN, i32::MIN, -8));
+    assert_eq!(a.wrapping_sub(b), a - b);
+    let (difference, overflow) = a.overflowing_sub(b);
+    assert_eq!(difference, a - b);
+    assert_eq!(overflow, U32x4::new(!0, 0, !0, 0));
+    assert_eq!(a.saturating_sub(b), I32x4::new(i32::MIN, i32::MIN, i32::MAX, -8));
+    let c = I32x4::new(0, -1, 0, i32::MIN);
+    let d = I32x4::new(i32::MIN, i32::MAX, i32::MAX, i32::MIN);
+    assert_eq!(c.overflowing_sub(d).1, U32x4::new(!0, 0, 0, 0));
+    assert_eq!(c.saturating_sub(d), I32x4::new(i32::MAX, i32::MIN, -i32::MAX, 0));
 }
 
 #[test]
@@ -667,6 +1620,435 @@ fn test_i32x4_swizzles() {
     assert_eq!(a.zwww(), I32x4::new(3, 4, 4, 4));
 }
 
+// U32x4
+
+#[test]
+fn test_u32x4_constructors_and_indexing() {
+    let mut a = U32x4::new(1, 2, 3, 4);
+    assert_eq!((a[0], a[1], a[2], a[3]), (1, 2, 3, 4));
+    assert_eq!(a.to_array(), [1, 2, 3, 4]);
+    a[2] = !0;
+    assert_eq!(a[2], !0);
+    assert_eq!(a, U32x4::new(1, 2, !0, 4));
+    a[0] += 10;
+    assert_eq!(a.to_array(), [11, 2, !0, 4]);
+}
+
+#[test]
+fn test_u32x4_loads_and_stores() {
+    #[repr(align(16))]
+    struct Aligned([u32; 4]);
+
+    let a = U32x4::from_array([1, 2, !0, 4]);
+    assert_eq!(a.to_array(), [1, 2, !0, 4]);
+    let mut aligned = Aligned([0; 4]);
+    unsafe {
+        a.store_aligned(aligned.0.as_mut_ptr());
+        assert_eq!(U32x4::load_aligned(aligned.0.as_ptr()), a);
+    }
+    let mut slice = [0; 4];
+    a.store(&mut slice);
+    assert_eq!(U32x4::load(&slice), a);
+    assert_eq!(U32x4::load_partial(&slice[1..]), U32x4::new(2, !0, 4, 0));
+    let mut short = [9; 2];
+    a.store_partial(&mut short);
+    assert_eq!(short, [1, 2]);
+}
+
+#[test]
+fn test_u32x4_bits() {
+    assert_eq!(U32x4::bit(5), U32x4::splat(32));
+    let flags = U32x4::new(0b0000, 0b0100, 0b1001, 0b1111);
+    assert_eq!(flags.test_bit(2), U32x4::new(0, !0, 0, !0));
+    assert_eq!(flags.test_bit(31), U32x4::splat(0));
+    let set = flags.set_bit(0);
+    assert_eq!(set, U32x4::new(0b0001, 0b0101, 0b1001, 0b1111));
+    assert_eq!(set.test_bit(0), U32x4::splat(!0));
+    assert_eq!(flags.clear_bit(3), U32x4::new(0b0000, 0b0100, 0b0001, 0b0111));
+    assert_eq!(flags.toggle_bit(1), U32x4::new(0b0010, 0b0110, 0b1011, 0b1101));
+    assert_eq!(U32x4::splat(0).set_bit(31), U32x4::splat(1 << 31));
+}
+
+#[test]
+fn test_u32x4_powers_of_two() {
+    let a = U32x4::new(1, 64, 1 << 31, 0);
+    assert_eq!(a.is_power_of_two(), U32x4::new(!0, !0, !0, 0));
+    assert_eq!(a.next_power_of_two(), U32x4::new(1, 64, 1 << 31, 1));
+    let b = U32x4::new(3, 100, (1 << 31) + 1, !0);
+    assert_eq!(b.is_power_of_two(), U32x4::splat(0));
+    assert_eq!(b.next_power_of_two(), U32x4::new(4, 128, 0, 0));
+    assert_eq!(U32x4::new(2, 5, 1023, 1025).next_power_of_two(), U32x4::new(2, 8, 1024, 2048));
+}
+
+#[test]
+fn test_u32x4_subtraction_overflow() {
+    let a = U32x4::new(0, 0, 5, !0);
+    let b = U32x4::new(1, 0, 7, 1 << 31);
+    assert_eq!(a - b, U32x4::new(!0, 0, !0 - 1, (1 << 31) - 1));
+    assert_eq!(a.wrapping_sub(b), a - b);
+    let (difference, overflow) = a.overflowing_sub(b);
+    assert_eq!(difference, a - b);
+    assert_eq!(overflow, U32x4::new(!0, 0, !0, 0));
+    assert_eq!(a.saturating_sub(b), U32x4::new(0, 0, 0, (1 << 31) - 1));
+    let c = U32x4::new(1 << 31, (1 << 31) - 1, !0, 1);
+    let d = U32x4::new((1 << 31) - 1, 1 << 31, !0, 0);
+    assert_eq!(c.saturating_sub(d), U32x4::new(1, 0, 0, 1));
+}
+
+#[test]
+fn test_u32x4_wrapping_arithmetic() {
+    let a = U32x4::new(u32::MAX, u32::MAX, 1 << 31, 7);
+    let b = U32x4::new(1, u32::MAX, 1 << 31, 6);
+    assert_eq!(a + b, U32x4::new(0, u32::MAX - 1, 0, 13));
+    assert_eq!(a * b, U32x4::new(u32::MAX, 1, 0, 42));
+    assert_eq!(U32x4::new(3, 0x8000_0001, 0x1_0001, 0) * U32x4::splat(0x1_0000),
+               U32x4::new(0x3_0000, 0x1_0000, 0x1_0000, 0));
+    let mut c = a;
+    c += b;
+    assert_eq!(c, a + b);
+    c -= b;
+    assert_eq!(c, a);
+    c *= b;
+    assert_eq!(c, a * b);
+}
+
+#[test]
+fn test_u32x4_unsigned_comparisons() {
+    let a = U32x4::new(0x8000_0000, 0x7fff_ffff, !0, 5);
+    let b = U32x4::new(0x7fff_ffff, 0x8000_0000, 0, 5);
+    assert_eq!(a.packed_gt(b), U32x4::new(!0, 0, !0, 0));
+    assert_eq!(a.packed_lt(b), U32x4::new(0, !0, 0, 0));
+    // A signed comparison gets the lanes that straddle the sign bit backwards.
+    assert_eq!(a.to_i32x4().packed_gt(b.to_i32x4()), U32x4::new(0, !0, 0, 0));
+}
+
+#[test]
+fn test_u32x4_movemask() {
+    let a = F32x4::new(1.0, 5.0, -2.0, 8.0);
+    assert_eq!(a.packed_gt(F32x4::splat(2.0)).movemask(), 0b1010);
+    assert_eq!(a.packed_gt(F32x4::splat(-3.0)).movemask(), 0b1111);
+    assert_eq!(a.packed_gt(F32x4::splat(8.0)).movemask(), 0b0000);
+    assert_eq!(a.packed_lt(F32x4::splat(1.5)).movemask(), 0b0101);
+    assert!(a.packed_gt(F32x4::splat(-3.0)).all());
+    assert!(a.packed_gt(F32x4::splat(7.0)).any() && !a.packed_gt(F32x4::splat(7.0)).all());
+    assert!(!a.packed_gt(F32x4::splat(8.0)).any());
+    assert_eq!(F32x4::new(-1.0, 0.0, -0.0, 3.0).movemask(), 0b0101);
+}
+
+#[test]
+fn test_u32x4_select() {
+    let mask = U32x4::new(!0, 0, 0, !0);
+    let a = F32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = F32x4::new(-1.0, -2.0, -3.0, -4.0);
+    assert_eq!(mask.select(a, b), F32x4::new(1.0, -2.0, -3.0, 4.0));
+}
+
+#[test]
+fn test_u32x4_select_branchless_max() {
+    let a = F32x4::new(1.0, -3.0, 7.5, 0.25);
+    let b = F32x4::new(2.0, -4.0, 7.0, 0.5);
+    assert_eq!(a.packed_gt(b).select(a, b), a.max(b));
+    assert_eq!(b.packed_gt(a).select(b, a), b.max(a));
+}
+
+#[test]
+fn test_u32x4_select_or() {
+    let valid = U32x4::new(!0, 0, !0, 0);
+    let floats = valid.select_or(F32x4::new(1.0, 2.0, 3.0, 4.0), -1.0);
+    assert_eq!(floats, F32x4::new(1.0, -1.0, 3.0, -1.0));
+    let ints = valid.select_or(I32x4::new(5, 6, 7, 8), i32::MIN);
+    assert_eq!(ints, I32x4::new(5, i32::MIN, 7, i32::MIN));
+    let uints = valid.select_or(U32x4::new(5, 6, 7, 8), !0);
+    assert_eq!(uints, U32x4::new(5, !0, 7, !0));
+}
+
+#[test]
+fn test_u32x4_bitselect() {
+    let mask = U32x4::new(0x0f0f_0f0f, !0, 0, 0xffff_0000);
+    let a = U32x4::splat(0xaaaa_aaaa);
+    let b = U32x4::splat(0x5555_5555);
+    let mixed = U32x4::new(0x5a5a_5a5a, 0xaaaa_aaaa, 0x5555_5555, 0xaaaa_5555);
+    assert_eq!(U32x4::bitselect(mask, a, b), mixed);
+    assert_eq!(mask.bitselect(a.to_i32x4(), b.to_i32x4()), mixed.to_i32x4());
+
+    // Taking the sign bit from one vector and the rest from another is `copysign`.
+    let sign = U32x4::splat(0x8000_0000);
+    let magnitudes = F32x4::new(1.5, -2.0, 0.0, -0.25);
+    let signs = F32x4::new(-1.0, 1.0, -0.0, -3.0);
+    let copied = sign.bitselect(signs, magnitudes);
+    assert_eq!(copied.to_i32x4_bits(), F32x4::new(-1.5, 2.0, -0.0, -0.25).to_i32x4_bits());
+}
+
+#[test]
+fn test_u32x4_variable_shifts() {
+    let a = U32x4::new(0x8000_0001, 0xffff_ffff, 3, 0x1234_5678);
+    let counts = U32x4::new(1, 31, 32, 0);
+    assert_eq!(a.shl_each(counts), U32x4::new(2, 0x8000_0000, 0, 0x1234_5678));
+    assert_eq!(a.shr_each(counts), U32x4::new(0x4000_0000, 1, 0, 0x1234_5678));
+    assert_eq!(a.shl_each(U32x4::new(4, 100, !0, 8)), U32x4::new(0x10, 0, 0, 0x3456_7800));
+}
+
+// U8x16
+
+#[test]
+fn test_u8x16_constructors_and_indexing() {
+    let mut a = U8x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 255);
+    assert_eq!(a.to_array(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 255]);
+    assert_eq!((a[0], a[7], a[15]), (0, 7, 255));
+    a[7] = 70;
+    assert_eq!(a[7], 70);
+    assert_eq!(a, U8x16::new(0, 1, 2, 3, 4, 5, 6, 70, 8, 9, 10, 11, 12, 13, 14, 255));
+    assert_ne!(a, U8x16::splat(0));
+    assert_eq!(format!("{:?}", U8x16::splat(7)),
+               "<7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7>");
+    assert_eq!(format!("{:?}", a),
+               "<0, 1, 2, 3, 4, 5, 6, 70, 8, 9, 10, 11, 12, 13, 14, 255>");
+}
+
+#[test]
+fn test_u8x16_saturating_arithmetic() {
+    let a = U8x16::from_array([250, 255, 0, 100, 128, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    let b = U8x16::from_array([10, 1, 0, 100, 128, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+    assert_eq!((a + b).to_array(),
+               [255, 255, 0, 200, 255, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+    assert_eq!((a - b).to_array(), [240, 254, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!((b - a).to_array(), [0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_u8x16_shuffle() {
+    let bytes = U8x16::from_array([10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25]);
+    let indices = U8x16::from_array([15, 0, 1, 0x80, 2, 0x13, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0xff]);
+    assert_eq!(bytes.shuffle(indices).to_array(),
+               [25, 10, 11, 0, 12, 13, 13, 14, 15, 16, 17, 18, 19, 20, 21, 0]);
+    assert_eq!(U8x16::from_array([1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80]).as_i32x4(),
+               I32x4::from_array([1, 1 << 8, 0, 0x8000_0000u32 as i32]));
+}
+
+#[test]
+fn test_u8x16_pack_i32x4s() {
+    let packed = U8x16::pack_i32x4s(I32x4::new(0, 1, 2, 3),
+                                    I32x4::new(-1, 255, 256, 70_000),
+                                    I32x4::new(i32::MIN, i32::MAX, 128, 127),
+                                    I32x4::new(12, 34, 56, 78));
+    assert_eq!(packed.to_array(), [0, 1, 2, 3, 0, 255, 255, 255, 0, 255, 128, 127, 12, 34, 56, 78]);
+}
+
+#[test]
+fn test_u8x16_packed_eq() {
+    let a = U8x16::from_array([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 0xff]);
+    let mask = a.packed_eq(U8x16::splat(3)).movemask();
+    assert_eq!(mask, 1 << 3);
+    assert_eq!(mask.trailing_zeros(), 3);
+    assert_eq!(a.packed_eq(U8x16::splat(0xff)).movemask(), 1 << 15);
+    assert_eq!(a.packed_eq(a).movemask(), 0xffff);
+    assert_eq!(a.movemask(), 1 << 15);
+}
+
+#[test]
+fn test_u8x16_avg() {
+    let a = U8x16::from_array([3, 4, 0, 255, 255, 1, 100, 7, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let b = U8x16::from_array([4, 3, 1, 255, 0, 1, 103, 8, 0, 0, 0, 0, 0, 0, 0, 255]);
+    // Halves round up, and the sum never overflows.
+    assert_eq!(a.avg(b).to_array(), [4, 4, 1, 255, 128, 1, 102, 8, 0, 0, 0, 0, 0, 0, 0, 128]);
+    assert_eq!(a.avg(b), b.avg(a));
+}
+
+#[test]
+fn test_u8x16_min_max_median3() {
+    let a = [0, 255, 7, 7, 1, 2, 3, 200, 128, 127, 9, 9, 50, 0, 255, 30];
+    let b = [255, 0, 7, 8, 2, 3, 1, 100, 127, 128, 9, 10, 40, 0, 254, 10];
+    let c = [128, 128, 7, 7, 3, 1, 2, 150, 129, 126, 8, 10, 60, 1, 253, 20];
+    let (va, vb) = (U8x16::from_array(a), U8x16::from_array(b));
+    assert_eq!(va.min(vb).to_array(), [0, 0, 7, 7, 1, 2, 1, 100, 127, 127, 9, 9, 40, 0, 254, 10]);
+    assert_eq!(va.max(vb).to_array(),
+               [255, 255, 7, 8, 2, 3, 3, 200, 128, 128, 9, 10, 50, 0, 255, 30]);
+    let mut expected = [0; 16];
+    for i in 0..16 {
+        let mut triple = [a[i], b[i], c[i]];
+        triple.sort_unstable();
+        expected[i] = triple[1];
+    }
+    let permutations = [(a, b, c), (a, c, b), (b, a, c), (b, c, a), (c, a, b), (c, b, a)];
+    for &(x, y, z) in &permutations {
+        let (x, y, z) = (U8x16::from_array(x), U8x16::from_array(y), U8x16::from_array(z));
+        assert_eq!(U8x16::median3(x, y, z).to_array(), expected);
+    }
+}
+
+#[test]
+fn test_u8x16_bilinear_blend() {
+    let tl = U8x16::from_array([0, 1, 2, 3, 40, 50, 60, 70, 80, 90, 100, 110, 120, 130, 140, 255]);
+    let tr = U8x16::splat(100);
+    let bl = U8x16::splat(200);
+    let br = U8x16::from_array([4, 8, 12, 16, 20, 24, 28, 32, 36, 40, 44, 48, 52, 56, 60, 255]);
+    let blend = |wx, wy| U8x16::bilinear_blend(tl, tr, bl, br, wx, wy).to_array();
+    assert_eq!(blend(0, 0), tl.to_array());
+    assert_eq!(blend(256, 0), tr.to_array());
+    assert_eq!(blend(0, 256), bl.to_array());
+    assert_eq!(blend(256, 256), br.to_array());
+    assert_eq!(blend(1000, 1000), br.to_array());
+
+    let center = blend(128, 128);
+    let (tl, tr, bl, br) = (tl.to_array(), tr.to_array(), bl.to_array(), br.to_array());
+    for i in 0..16 {
+        let average = (tl[i] as u32 + tr[i] as u32 + bl[i] as u32 + br[i] as u32) as f32 / 4.0;
+        assert!((center[i] as f32 - average).abs() <= 1.0);
+    }
+    let (a, b, c, d) = (U8x16::splat(10), U8x16::splat(30), U8x16::splat(50), U8x16::splat(70));
+    assert_eq!(U8x16::bilinear_blend(a, b, c, d, 128, 128).to_array(), [40; 16]);
+}
+
+// F32x8
+
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+#[test]
+fn test_f32x8_constructors() {
+    let a = F32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+    assert_eq!(a.to_array(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert_eq!((a[0], a[7]), (1.0, 8.0));
+    assert_eq!(F32x8::splat(7.0).to_array(), [7.0; 8]);
+    assert_eq!(F32x8::default(), F32x8::splat(0.0));
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+#[test]
+fn test_f32x8_arithmetic() {
+    let a = F32x8::new(4.0, 3.0, 2.0, 1.0, -1.0, -2.0, -3.0, -4.0);
+    let b = F32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+    assert_eq!(a + b, F32x8::new(5.0, 5.0, 5.0, 5.0, 4.0, 4.0, 4.0, 4.0));
+    assert_eq!(a - b, F32x8::new(3.0, 1.0, -1.0, -3.0, -6.0, -8.0, -10.0, -12.0));
+    assert_eq!(a * b, F32x8::new(4.0, 6.0, 6.0, 4.0, -5.0, -12.0, -21.0, -32.0));
+    assert_eq!(a / F32x8::splat(2.0), F32x8::new(2.0, 1.5, 1.0, 0.5, -0.5, -1.0, -1.5, -2.0));
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+#[test]
+fn test_f32x8_basic_ops() {
+    let a = F32x8::new(1.0, 3.0, 5.0, 7.0, -1.0, -3.0, -5.0, -7.0);
+    let b = F32x8::new(2.0, 2.0, 6.0, 6.0, -2.0, -2.0, -6.0, -6.0);
+    assert_eq!(a.min(b), F32x8::new(1.0, 2.0, 5.0, 6.0, -2.0, -3.0, -6.0, -7.0));
+    assert_eq!(a.max(b), F32x8::new(2.0, 3.0, 6.0, 7.0, -1.0, -2.0, -5.0, -6.0));
+    assert_eq!(a.abs(), F32x8::new(1.0, 3.0, 5.0, 7.0, 1.0, 3.0, 5.0, 7.0));
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+#[test]
+fn test_f32x8_packed_comparisons() {
+    let a = F32x8::new(7.0, 3.0, 6.0, -2.0, 0.0, 1.0, f32::NAN, 4.0);
+    let b = F32x8::new(10.0, 3.0, 5.0, -2.0, 1.0, 0.0, 0.0, 4.0);
+    assert_eq!(a.packed_eq(b).movemask(), 0b1000_1010);
+    assert_eq!(a.packed_gt(b).movemask(), 0b0010_0100);
+    assert_eq!(a.packed_lt(b).movemask(), 0b0001_0001);
+    assert_eq!(a.packed_le(b).movemask(), 0b1101_1011);
+    assert_eq!(a.packed_eq(a).to_array(), [!0, !0, !0, !0, !0, !0, 0, !0]);
+    assert!(b.packed_eq(b).all_true() && a.packed_gt(F32x8::splat(10.0)).all_false());
+    let mask: U32x8 = a.packed_lt(b);
+    assert_eq!((mask[0], mask[1]), (!0, 0));
+}
+
+// I32x8
+
+#[cfg(all(feature = "avx", target_feature = "avx2"))]
+#[test]
+fn test_i32x8_constructors() {
+    let a = I32x8::new(1, -2, 3, -4, 5, -6, 7, -8);
+    assert_eq!(a.to_array(), [1, -2, 3, -4, 5, -6, 7, -8]);
+    assert_eq!((a[0], a[7]), (1, -8));
+    assert_eq!(I32x8::splat(9).to_array(), [9; 8]);
+    assert_eq!(I32x8::default(), I32x8::splat(0));
+    let mut b = a;
+    b[3] = 40;
+    assert_eq!(b, I32x8::new(1, -2, 3, 40, 5, -6, 7, -8));
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx2"))]
+#[test]
+fn test_i32x8_basic_ops() {
+    let a = I32x8::new(6, 29, -40, 2, 0, i32::MAX, -1, 8);
+    let b = I32x8::new(10, -5, 10, 46, 0, i32::MIN, 1, 8);
+    assert_eq!(a.min(b), I32x8::new(6, -5, -40, 2, 0, i32::MIN, -1, 8));
+    assert_eq!(a.max(b), I32x8::new(10, 29, 10, 46, 0, i32::MAX, 1, 8));
+    let c = I32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+    let d = I32x8::new(8, 7, 6, 5, 4, 3, 2, 1);
+    assert_eq!(c + d, I32x8::splat(9));
+    assert_eq!(c - d, I32x8::new(-7, -5, -3, -1, 1, 3, 5, 7));
+    assert_eq!(c * d, I32x8::new(8, 14, 18, 20, 20, 18, 14, 8));
+    assert_eq!(c.to_f32x8(), F32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0));
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx2"))]
+#[test]
+fn test_i32x8_packed_comparisons() {
+    let a = I32x8::new(59, 1, 5, 63, -3, 0, 7, i32::MIN);
+    let b = I32x8::new(-59, 1, 5, 36, -3, 1, 8, i32::MAX);
+    assert_eq!(a.packed_eq(b).movemask(), 0b0001_0110);
+    assert_eq!(a.packed_gt(b).movemask(), 0b0000_1001);
+    assert_eq!(a.packed_gt(b).to_array(), [!0, 0, 0, !0, 0, 0, 0, 0]);
+}
+
+// Statistics
+
+#[test]
+fn test_f32_stats() {
+    let stats = F32Stats::new();
+    assert_eq!((stats.count(), stats.mean(), stats.variance()), (0, 0.0, 0.0));
+
+    let samples: Vec<f32> = (0..1000).map(|i| 1000.0 + ((i * 37) % 101) as f32 * 0.25).collect();
+    let mut stats = F32Stats::new();
+    for chunk in samples.chunks_exact(4) {
+        stats.update(F32x4::load(chunk));
+    }
+    let count = samples.len() as f64;
+    let mean = samples.iter().map(|&x| x as f64).sum::<f64>() / count;
+    let variance = samples.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / count;
+    assert_eq!(stats.count(), 1000);
+    assert!((stats.mean() as f64 - mean).abs() < 1e-3);
+    assert!((stats.variance() as f64 - variance).abs() < variance * 1e-4);
+}
+
+// Masks
+
+fn count_and_blend<M: SimdMask>(mask: M, a: M, b: M) -> (u32, M) {
+    (mask.count_true(), (mask & a) | (!mask & b))
+}
+
+#[test]
+fn test_simd_mask() {
+    let mask = F32x4::new(1.0, 5.0, -2.0, 8.0).packed_gt(F32x4::splat(2.0));
+    assert_eq!(SimdMask::movemask(mask), 0b1010);
+    assert!(mask.any() && !mask.all());
+    let (count, blended) = count_and_blend(mask, U32x4::new(1, 2, 3, 4), U32x4::new(5, 6, 7, 8));
+    assert_eq!(count, 2);
+    assert_eq!(blended, U32x4::new(5, 2, 7, 4));
+    assert!(U32x4::splat(!0).all());
+    assert!(!U32x4::splat(0).any());
+    assert_eq!(U32x4::splat(!0).count_true(), 4);
+
+    let bytes = U8x16::from_array([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    let mask = bytes.packed_eq(U8x16::splat(3)) | bytes.packed_eq(U8x16::splat(12));
+    assert_eq!(SimdMask::movemask(mask), 1 << 3 | 1 << 12);
+    let (count, blended) = count_and_blend(mask, U8x16::splat(0xaa), bytes);
+    assert_eq!(count, 2);
+    assert_eq!(blended.to_array(), [0, 1, 2, 0xaa, 4, 5, 6, 7, 8, 9, 10, 11, 0xaa, 13, 14, 15]);
+    assert!(U8x16::splat(!0).all() && !U8x16::splat(0).any());
+    assert_eq!(U8x16::splat(!0).count_true(), 16);
+}
+
+#[cfg(all(feature = "avx", target_feature = "avx"))]
+#[test]
+fn test_simd_mask_u32x8() {
+    let a = F32x8::new(1.0, 5.0, -2.0, 8.0, 3.0, 0.0, 9.0, 2.0);
+    let mask = a.packed_gt(F32x8::splat(2.0));
+    assert_eq!(SimdMask::movemask(mask), 0b0101_1010);
+    assert!(mask.any() && !mask.all());
+    let all = a.packed_eq(a);
+    let (count, blended) = count_and_blend(mask, all, !all);
+    assert_eq!(count, 4);
+    assert_eq!(blended, mask);
+    assert_eq!((mask ^ all).count_true(), 4);
+}
+
 // Scalar F32x4
 
 #[test]
@@ -689,3 +2071,331 @@ fn test_f32x4s_basic_ops() {
     assert_eq!(c.ceil(), F32x4S::new(-1.0, 2.0, -20.0, 4.0));
     assert_eq!(c.to_i32x4().to_f32x4(), F32x4S::new(-1.0, 1.0, -20.0, 4.0));
 }
+
+// Kernels
+
+#[test]
+fn test_mask_to_indices() {
+    let mut indices = [0xff; 16];
+    assert_eq!(mask_to_indices(0, &mut indices), 0);
+    assert_eq!(indices, [0xff; 16]);
+    assert_eq!(mask_to_indices(1 << 9, &mut indices), 1);
+    assert_eq!(indices[0], 9);
+    assert_eq!(mask_to_indices(0b1000_0000_0010_1101, &mut indices), 5);
+    assert_eq!(&indices[0..5], &[0, 2, 3, 5, 15]);
+    assert_eq!(mask_to_indices(0xffff, &mut indices), 16);
+    assert_eq!(indices, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+#[test]
+fn test_reduce_lanes() {
+    let points = [
+        1.0, -2.0, 0.0, 1.0,
+        -3.0, 5.0, 0.5, 1.0,
+        4.0, 0.0, -0.5, 1.0,
+    ];
+    assert_eq!(reduce_min_lanes(&points), F32x4::new(-3.0, -2.0, -0.5, 1.0));
+    assert_eq!(reduce_max_lanes(&points), F32x4::new(4.0, 5.0, 0.5, 1.0));
+    assert_eq!(reduce_min_lanes(&points[0..4]), F32x4::new(1.0, -2.0, 0.0, 1.0));
+    assert_eq!(reduce_max_lanes(&[]), F32x4::splat(f32::NEG_INFINITY));
+}
+
+#[test]
+fn test_blend() {
+    let a: Vec<f32> = (0..11).map(|i| i as f32).collect();
+    let b: Vec<f32> = (0..11).map(|i| -(i as f32)).collect();
+    let mask = [1, 0, !0, 0, 0, 0, 7, 1, 0, 1, 0];
+    let mut dst = vec![0.0; 11];
+    blend(&mut dst, &a, &b, &mask);
+    assert_eq!(dst, [0.0, -1.0, 2.0, -3.0, -4.0, -5.0, 6.0, 7.0, -8.0, 9.0, -10.0]);
+}
+
+#[test]
+fn test_rms_slice() {
+    assert_eq!(rms_slice(&[3.0, 4.0, 0.0, 0.0]), 2.5);
+    assert_eq!(rms_slice(&[]), 0.0);
+    let samples: Vec<f32> = (0..11).map(|i| (i as f32 - 5.0) * 0.5).collect();
+    let expected = (samples.iter().map(|x| x * x).sum::<f32>() / 11.0).sqrt();
+    assert!((rms_slice(&samples) - expected).abs() < 1e-5);
+}
+
+#[test]
+fn test_saxpy_strided() {
+    let x: Vec<f32> = (0..23).map(|i| i as f32).collect();
+    let y: Vec<f32> = (0..23).map(|i| 100.0 - 2.0 * i as f32).collect();
+    for &(stride, offset) in &[(1, 0), (1, 3), (4, 0), (4, 2)] {
+        let mut expected = y.clone();
+        for index in (offset..x.len()).step_by(stride) {
+            expected[index] += 0.5 * x[index];
+        }
+        let mut actual = y.clone();
+        saxpy_strided(0.5, &x, &mut actual, stride, offset);
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_poly_eval() {
+    let coeffs = [1.0, -2.0, 0.5, 3.0, -0.25];
+    let horner = |x: f32| {
+        coeffs.iter().rev().fold(0.0, |value, &coefficient| value * x + coefficient)
+    };
+    for &len in &[0, 3, 8, 11] {
+        let xs: Vec<f32> = (0..len).map(|i| i as f32 * 0.375 - 1.5).collect();
+        let mut out = vec![0.0; len];
+        poly_eval(&coeffs, &xs, &mut out);
+        for (&actual, &x) in out.iter().zip(&xs) {
+            let expected = horner(x);
+            assert!((actual - expected).abs() <= 1e-5 * expected.abs().max(1.0),
+                    "p({}) = {} != {}", x, actual, expected);
+        }
+    }
+    assert_eq!(F32x4::splat(2.0).poly_horner(&[]), F32x4::default());
+    assert_eq!(F32x4::new(0.0, 1.0, 2.0, -1.0).poly_horner(&coeffs),
+               F32x4::new(1.0, 2.25, 19.0, 0.25));
+}
+
+#[test]
+fn test_convolve_symmetric() {
+    let gaussian: Vec<f32> = [1.0, 4.0, 6.0, 4.0, 1.0].iter().map(|tap| tap / 16.0).collect();
+    let kernels = [vec![1.0 / 3.0; 3], gaussian];
+    for kernel in &kernels {
+        for &len in &[1, 2, 7, 12] {
+            let input: Vec<f32> = (0..len).map(|i| ((i * 7) % 5) as f32 - 1.5).collect();
+            let radius = kernel.len() as isize / 2;
+            let expected: Vec<f32> = (0..len as isize).map(|i| {
+                kernel.iter().enumerate().map(|(k, tap)| {
+                    let index = (i + k as isize - radius).max(0).min(len as isize - 1);
+                    tap * input[index as usize]
+                }).sum()
+            }).collect();
+            let mut output = vec![0.0; len];
+            convolve_symmetric(&input, &mut output, kernel);
+            for (actual, expected) in output.iter().zip(&expected) {
+                assert!((actual - expected).abs() < 1e-5, "{} != {}", actual, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_count_byte() {
+    let haystack: Vec<u8> = (0..37).map(|i| (i % 5) as u8).collect();
+    assert_eq!(count_byte(&haystack, 9), 0);
+    assert_eq!(count_byte(&haystack, 2), 7);
+    assert_eq!(count_byte(&haystack[0..16], 4), 3);
+    assert_eq!(count_byte(&[0x80; 35], 0x80), 35);
+    assert_eq!(count_byte(&[], 0), 0);
+}
+
+#[test]
+fn test_histogram_u8() {
+    let mut seed = 0x1234_5678u32;
+    let pixels: Vec<u8> = (0..1001).map(|_| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as u8
+    }).collect();
+    let mut expected = [1; 256];
+    for &pixel in &pixels {
+        expected[pixel as usize] += 1;
+    }
+    let mut bins = [1; 256];
+    histogram_u8(&pixels, &mut bins);
+    assert_eq!(&bins[..], &expected[..]);
+
+    let mut bins = [0; 256];
+    histogram_u8(&[42; 37], &mut bins);
+    assert_eq!(bins[42], 37);
+    assert_eq!(bins.iter().sum::<u32>(), 37);
+}
+
+#[test]
+fn test_f32_to_u8() {
+    let mut input: Vec<f32> = (0..37).map(|i| (i as f32 - 2.0) / 32.0).collect();
+    input[5] = f32::NAN;
+    input[6] = 0.5 / 255.0;
+    input[7] = 1.5 / 255.0;
+    input[8] = 2.5 / 255.0;
+    input[36] = -1.0;
+    let mut output = vec![0; input.len()];
+    f32_to_u8(&input, &mut output);
+    for (&float, &byte) in input.iter().zip(&output) {
+        let scaled = float.clamp(0.0, 1.0) * 255.0;
+        let mut rounded = scaled.round();
+        if (rounded - scaled).abs() == 0.5 && rounded % 2.0 != 0.0 {
+            rounded -= (rounded - scaled).signum();
+        }
+        assert_eq!(byte, rounded as u8, "{} -> {}", float, byte);
+    }
+    assert_eq!(&output[0..9], &[0, 0, 0, 8, 16, 0, 0, 2, 2]);
+    assert_eq!(output[36], 0);
+    assert_eq!(output[35], 255);
+}
+
+#[test]
+fn test_rgba_interleaving() {
+    for &pixel_count in &[0, 3, 4, 9] {
+        let interleaved: Vec<u8> = (0..pixel_count * 4).map(|i| (i * 3 + 1) as u8).collect();
+        let (mut r, mut g) = (vec![0; pixel_count], vec![0; pixel_count]);
+        let (mut b, mut a) = (vec![0; pixel_count], vec![0; pixel_count]);
+        deinterleave_rgba(&interleaved, &mut r, &mut g, &mut b, &mut a);
+        for index in 0..pixel_count {
+            assert_eq!([r[index], g[index], b[index], a[index]],
+                       [interleaved[index * 4],
+                        interleaved[index * 4 + 1],
+                        interleaved[index * 4 + 2],
+                        interleaved[index * 4 + 3]]);
+        }
+        let mut round_trip = vec![0; pixel_count * 4];
+        interleave_rgba(&r, &g, &b, &a, &mut round_trip);
+        assert_eq!(round_trip, interleaved);
+    }
+}
+
+#[test]
+fn test_dot3_batch() {
+    for &count in &[0, 3, 4, 9] {
+        let a: Vec<f32> = (0..count * 3).map(|i| i as f32 * 0.5 - 3.0).collect();
+        let b: Vec<f32> = (0..count * 3).map(|i| (i % 7) as f32 - 2.0).collect();
+        let mut out = vec![0.0; count];
+        dot3_batch(&a, &b, &mut out);
+        for (i, &dot) in out.iter().enumerate() {
+            let expected = a[i * 3] * b[i * 3] + a[i * 3 + 1] * b[i * 3 + 1] +
+                a[i * 3 + 2] * b[i * 3 + 2];
+            assert_eq!(dot, expected);
+        }
+    }
+}
+
+#[test]
+fn test_srgb_round_trip() {
+    let srgb_to_linear = |c: f32| {
+        if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+    let bytes: Vec<u8> = (0..=255).chain(250..=255).collect();
+    let mut linear = vec![0.0; bytes.len()];
+    srgb_decode_u8(&bytes, &mut linear);
+    for (&byte, &value) in bytes.iter().zip(linear.iter()) {
+        assert!((value - srgb_to_linear(byte as f32 / 255.0)).abs() < 1e-6);
+    }
+    assert_eq!((linear[0], linear[255]), (0.0, 1.0));
+    let mut encoded = vec![0; bytes.len()];
+    srgb_encode_f32(&linear, &mut encoded);
+    assert_eq!(encoded, bytes);
+
+    let floats: Vec<f32> = (0..37).map(|i| i as f32 / 36.0).collect();
+    let mut quantized = vec![0; floats.len()];
+    srgb_encode_f32(&floats, &mut quantized);
+    let mut decoded = vec![0.0; floats.len()];
+    srgb_decode_u8(&quantized, &mut decoded);
+    for (&float, &value) in floats.iter().zip(decoded.iter()) {
+        assert!((value - float).abs() < 0.01);
+    }
+    assert_eq!((quantized[0], quantized[36]), (0, 255));
+}
+
+#[test]
+fn test_downscale_2x_rgba() {
+    // A solid color stays the same.
+    let mut dst = [0; 3 * 2 * 4];
+    downscale_2x_rgba(&[10, 20, 30, 255].repeat(6 * 4), 6, 4, &mut dst);
+    assert_eq!(dst.to_vec(), [10, 20, 30, 255].repeat(3 * 2));
+
+    // A one-pixel checkerboard averages to gray.
+    let checkerboard: Vec<u8> = (0..8 * 4).flat_map(|i| {
+        let value = if (i % 8 + i / 8) % 2 == 0 { 255 } else { 0 };
+        vec![value, value, value, 255]
+    }).collect();
+    let mut dst = [0; 4 * 2 * 4];
+    downscale_2x_rgba(&checkerboard, 8, 4, &mut dst);
+    assert_eq!(dst.to_vec(), [128, 128, 128, 255].repeat(4 * 2));
+
+    // Odd sizes average the last column and row with themselves.
+    for &(width, height) in &[(7usize, 5usize), (9, 3), (1, 1), (3, 2)] {
+        let src: Vec<u8> = (0..width * height * 4).map(|i| (i * 37 % 251) as u8).collect();
+        let (dst_width, dst_height) = (width.div_ceil(2), height.div_ceil(2));
+        let mut dst = vec![0; dst_width * dst_height * 4];
+        downscale_2x_rgba(&src, width, height, &mut dst);
+        for y in 0..dst_height {
+            for x in 0..dst_width {
+                for channel in 0..4 {
+                    let texel = |x: usize, y: usize| {
+                        src[(y.min(height - 1) * width + x.min(width - 1)) * 4 + channel] as u32
+                    };
+                    let sum = texel(x * 2, y * 2) + texel(x * 2 + 1, y * 2) +
+                        texel(x * 2, y * 2 + 1) + texel(x * 2 + 1, y * 2 + 1);
+                    let actual = dst[(y * dst_width + x) * 4 + channel] as u32;
+                    assert!(actual * 4 >= sum && actual * 4 <= sum + 6);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_prefix_sum() {
+    let ramp: Vec<f32> = (1..=11).map(|i| i as f32).collect();
+    let mut output = vec![0.0; ramp.len()];
+    prefix_sum(&ramp, &mut output);
+    let expected: Vec<f32> = (1..=11).map(|i| (i * (i + 1) / 2) as f32).collect();
+    assert_eq!(output, expected);
+
+    let mut seed = 0x2468_ace1u32;
+    let input: Vec<f32> = (0..103).map(|_| {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+    }).collect();
+    let mut output = vec![0.0; input.len()];
+    prefix_sum(&input, &mut output);
+    let mut running = 0.0;
+    for (&x, &sum) in input.iter().zip(output.iter()) {
+        running += x;
+        assert!((sum - running).abs() < 1e-4);
+    }
+
+    prefix_sum(&[], &mut []);
+}
+
+#[test]
+fn test_accumulate_weighted() {
+    let layers: Vec<Vec<f32>> = (0..2).map(|layer| {
+        (0..11).map(|i| (i * 3 + layer * 7) as f32 * 0.125 - 1.0).collect()
+    }).collect();
+    let weights = [0.75, -0.5];
+    let mut dst = vec![0.5; 11];
+    for (layer, &weight) in layers.iter().zip(weights.iter()) {
+        accumulate_weighted(&mut dst, layer, weight);
+    }
+    for i in 0..dst.len() {
+        let expected = 0.5 + weights[0] * layers[0][i] + weights[1] * layers[1][i];
+        assert!((dst[i] - expected).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_transpose() {
+    let rows = [
+        F32x4::new(0.0, 1.0, 2.0, 3.0),
+        F32x4::new(4.0, 5.0, 6.0, 7.0),
+        F32x4::new(8.0, 9.0, 10.0, 11.0),
+        F32x4::new(12.0, 13.0, 14.0, 15.0),
+    ];
+    let columns = transpose_4x4(rows);
+    assert_eq!(columns[0], F32x4::new(0.0, 4.0, 8.0, 12.0));
+    assert_eq!(columns[3], F32x4::new(3.0, 7.0, 11.0, 15.0));
+    assert_eq!(transpose_4x4(columns), rows);
+
+    // Full blocks only, ragged edges only, and both.
+    for &(rows, cols) in &[(8, 8), (4, 8), (5, 3), (6, 9), (1, 7), (0, 4)] {
+        let src: Vec<f32> = (0..rows * cols).map(|i| i as f32).collect();
+        let mut dst = vec![-1.0; rows * cols];
+        transpose(&src, rows, cols, &mut dst);
+        for row in 0..rows {
+            for col in 0..cols {
+                assert_eq!(dst[col * rows + row], src[row * cols + col]);
+            }
+        }
+    }
+}
@@ -0,0 +1,1556 @@
+// pathfinder/simd/src/wasm/mod.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The WebAssembly backend, built on the `simd128` proposal.
+//!
+//! Every vector type wraps the same untyped `v128`, so reinterpreting one as another is free.
+
+use std::arch::wasm32::{self, v128};
+use std::cmp::PartialEq;
+use std::fmt::{self, Debug, Formatter};
+use std::mem;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Not, Shl, Shr, Sub};
+
+mod swizzle_f32x4;
+mod swizzle_i32x4;
+
+// Two 32-bit floats
+
+#[derive(Clone, Copy)]
+pub struct F32x2(pub u64);
+
+impl F32x2 {
+    // Constructors
+
+    #[inline]
+    pub fn new(a: f32, b: f32) -> F32x2 {
+        F32x2(a.to_bits() as u64 | ((b.to_bits() as u64) << 32))
+    }
+
+    #[inline]
+    pub fn splat(x: f32) -> F32x2 {
+        F32x2::new(x, x)
+    }
+
+    // Basic operations
+
+    #[inline]
+    pub fn approx_recip(self) -> F32x2 {
+        self.to_f32x4().approx_recip().xy()
+    }
+
+    #[inline]
+    pub fn min(self, other: F32x2) -> F32x2 {
+        self.to_f32x4().min(other.to_f32x4()).xy()
+    }
+
+    #[inline]
+    pub fn max(self, other: F32x2) -> F32x2 {
+        self.to_f32x4().max(other.to_f32x4()).xy()
+    }
+
+    #[inline]
+    pub fn clamp(self, min: F32x2, max: F32x2) -> F32x2 {
+        self.to_f32x4().clamp(min.to_f32x4(), max.to_f32x4()).xy()
+    }
+
+    #[inline]
+    pub fn abs(self) -> F32x2 {
+        self.to_f32x4().abs().xy()
+    }
+
+    #[inline]
+    pub fn floor(self) -> F32x2 {
+        self.to_f32x4().floor().xy()
+    }
+
+    #[inline]
+    pub fn ceil(self) -> F32x2 {
+        self.to_f32x4().ceil().xy()
+    }
+
+    #[inline]
+    pub fn sqrt(self) -> F32x2 {
+        self.to_f32x4().sqrt().xy()
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: F32x2) -> U32x2 {
+        self.to_f32x4().packed_eq(other.to_f32x4()).xy()
+    }
+
+    #[inline]
+    pub fn packed_gt(self, other: F32x2) -> U32x2 {
+        self.to_f32x4().packed_gt(other.to_f32x4()).xy()
+    }
+
+    #[inline]
+    pub fn packed_lt(self, other: F32x2) -> U32x2 {
+        self.to_f32x4().packed_lt(other.to_f32x4()).xy()
+    }
+
+    #[inline]
+    pub fn packed_le(self, other: F32x2) -> U32x2 {
+        self.to_f32x4().packed_le(other.to_f32x4()).xy()
+    }
+
+    // Conversions
+
+    #[inline]
+    pub fn to_f32x4(self) -> F32x4 {
+        F32x4(wasm32::u64x2(self.0, 0))
+    }
+
+    #[inline]
+    pub fn to_i32x2(self) -> I32x2 {
+        self.to_i32x4().xy()
+    }
+
+    #[inline]
+    pub fn to_i32x4(self) -> I32x4 {
+        self.to_f32x4().to_i32x4()
+    }
+
+    // Swizzle
+
+    #[inline]
+    pub fn yx(self) -> F32x2 {
+        self.to_f32x4().yx()
+    }
+
+    // Concatenations
+
+    #[inline]
+    pub fn concat_xy_xy(self, other: F32x2) -> F32x4 {
+        F32x4(wasm32::u64x2(self.0, other.0))
+    }
+}
+
+impl Default for F32x2 {
+    #[inline]
+    fn default() -> F32x2 {
+        F32x2(0)
+    }
+}
+
+impl Index<usize> for F32x2 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, index: usize) -> &f32 {
+        unsafe { &mem::transmute::<&u64, &[f32; 2]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for F32x2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        unsafe { &mut mem::transmute::<&mut u64, &mut [f32; 2]>(&mut self.0)[index] }
+    }
+}
+
+impl Debug for F32x2 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}>", self[0], self[1])
+    }
+}
+
+impl PartialEq for F32x2 {
+    #[inline]
+    fn eq(&self, other: &F32x2) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+impl Add<F32x2> for F32x2 {
+    type Output = F32x2;
+    #[inline]
+    fn add(self, other: F32x2) -> F32x2 {
+        (self.to_f32x4() + other.to_f32x4()).xy()
+    }
+}
+
+impl Div<F32x2> for F32x2 {
+    type Output = F32x2;
+    #[inline]
+    fn div(self, other: F32x2) -> F32x2 {
+        (self.to_f32x4() / other.to_f32x4()).xy()
+    }
+}
+
+impl Mul<F32x2> for F32x2 {
+    type Output = F32x2;
+    #[inline]
+    fn mul(self, other: F32x2) -> F32x2 {
+        (self.to_f32x4() * other.to_f32x4()).xy()
+    }
+}
+
+impl Sub<F32x2> for F32x2 {
+    type Output = F32x2;
+    #[inline]
+    fn sub(self, other: F32x2) -> F32x2 {
+        (self.to_f32x4() - other.to_f32x4()).xy()
+    }
+}
+
+// Four 32-bit floats
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct F32x4(pub v128);
+
+impl F32x4 {
+    // Constructors
+
+    #[inline]
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> F32x4 {
+        F32x4(wasm32::f32x4(a, b, c, d))
+    }
+
+    #[inline]
+    pub fn splat(x: f32) -> F32x4 {
+        F32x4(wasm32::f32x4_splat(x))
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [f32; 4]) -> F32x4 {
+        F32x4::new(array[0], array[1], array[2], array[3])
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] {
+        unsafe { mem::transmute::<v128, [f32; 4]>(self.0) }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, even though WebAssembly loads themselves accept any address.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const f32) -> F32x4 {
+        F32x4(wasm32::v128_load(ptr as *const v128))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, even though WebAssembly stores themselves accept any address.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut f32) {
+        wasm32::v128_store(ptr as *mut v128, self.0)
+    }
+
+    /// Loads the float at byte address `base + indices[i] * stride_bytes` into each lane `i`.
+    ///
+    /// WebAssembly has no gather instruction, so this loads one lane at a time.
+    ///
+    /// # Safety
+    ///
+    /// Every computed address must be valid for an unaligned read of an `f32`.
+    #[inline]
+    pub unsafe fn gather_stride(base: *const u8, indices: I32x4, stride_bytes: i32) -> F32x4 {
+        let load = |i: usize| {
+            let offset = indices[i] as isize * stride_bytes as isize;
+            (base.offset(offset) as *const f32).read_unaligned()
+        };
+        F32x4::new(load(0), load(1), load(2), load(3))
+    }
+
+    // Basic operations
+
+    /// Returns the reciprocal of each lane. WebAssembly has no estimate instruction, so this is
+    /// an exact division.
+    #[inline]
+    pub fn approx_recip(self) -> F32x4 {
+        F32x4::splat(1.0) / self
+    }
+
+    /// Returns the reciprocal square root of each lane. WebAssembly has no estimate instruction,
+    /// so this is an exact square root and division.
+    #[inline]
+    pub fn approx_rsqrt(self) -> F32x4 {
+        F32x4::splat(1.0) / self.sqrt()
+    }
+
+    /// Computes `self * b + c`.
+    ///
+    /// `simd128` has no fused multiply-add, so the product is rounded before the addition.
+    #[inline]
+    pub fn mul_add(self, b: F32x4, c: F32x4) -> F32x4 {
+        self * b + c
+    }
+
+    /// Returns the dot product of all four lanes of this vector and `other`.
+    #[inline]
+    pub fn dot(self, other: F32x4) -> f32 {
+        let products = self * other;
+        let pairs = products + products.zwxy();
+        wasm32::f32x4_extract_lane::<0>((pairs + pairs.yxwz()).0)
+    }
+
+    /// Returns the lesser of each pair of lanes, or `other` where either is NaN, as `minps` does
+    /// on x86.
+    #[inline]
+    pub fn min(self, other: F32x4) -> F32x4 {
+        // `f32x4.pmin(a, b)` is `b < a ? b : a`, unlike `f32x4.min`, which propagates NaNs.
+        F32x4(wasm32::f32x4_pmin(other.0, self.0))
+    }
+
+    /// Returns the greater of each pair of lanes, or `other` where either is NaN, as `maxps` does
+    /// on x86.
+    #[inline]
+    pub fn max(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::f32x4_pmax(other.0, self.0))
+    }
+
+    /// Clamps each lane to the range `[min, max]`.
+    ///
+    /// As on x86, a NaN lane in `self` becomes `min`. The result for NaN bounds differs between
+    /// backends.
+    #[inline]
+    pub fn clamp(self, min: F32x4, max: F32x4) -> F32x4 {
+        self.max(min).min(max)
+    }
+
+    #[inline]
+    pub fn abs(self) -> F32x4 {
+        F32x4(wasm32::f32x4_abs(self.0))
+    }
+
+    #[inline]
+    pub fn floor(self) -> F32x4 {
+        F32x4(wasm32::f32x4_floor(self.0))
+    }
+
+    #[inline]
+    pub fn ceil(self) -> F32x4 {
+        F32x4(wasm32::f32x4_ceil(self.0))
+    }
+
+    /// Rounds each lane to the nearest integer, with ties going to the even integer, so `2.5`
+    /// rounds to `2.0` and `3.5` rounds to `4.0`.
+    #[inline]
+    pub fn round(self) -> F32x4 {
+        F32x4(wasm32::f32x4_nearest(self.0))
+    }
+
+    /// Returns the square root of each lane. Negative lanes become NaN, and NaN lanes pass
+    /// through.
+    #[inline]
+    pub fn sqrt(self) -> F32x4 {
+        F32x4(wasm32::f32x4_sqrt(self.0))
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: F32x4) -> U32x4 {
+        U32x4(wasm32::f32x4_eq(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn packed_gt(self, other: F32x4) -> U32x4 {
+        U32x4(wasm32::f32x4_gt(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn packed_lt(self, other: F32x4) -> U32x4 {
+        U32x4(wasm32::f32x4_lt(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn packed_le(self, other: F32x4) -> U32x4 {
+        U32x4(wasm32::f32x4_le(self.0, other.0))
+    }
+
+    /// Packs the sign bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        wasm32::i32x4_bitmask(self.0) as u32
+    }
+
+    // Conversions
+
+    /// Converts these packed floats to integers via rounding.
+    ///
+    /// Out-of-range lanes saturate, and NaN lanes become 0.
+    #[inline]
+    pub fn to_i32x4(self) -> I32x4 {
+        I32x4(wasm32::i32x4_trunc_sat_f32x4(wasm32::f32x4_nearest(self.0)))
+    }
+
+    /// Converts these packed floats to integers by truncating toward zero, with the same
+    /// saturating semantics as `as i32`: out-of-range lanes clamp to `i32::MIN` or `i32::MAX`, and
+    /// NaN lanes become 0.
+    #[inline]
+    pub fn to_i32x4_saturating(self) -> I32x4 {
+        I32x4(wasm32::i32x4_trunc_sat_f32x4(self.0))
+    }
+
+    /// Reinterprets the bits of these packed floats as integers.
+    #[inline]
+    pub fn to_i32x4_bits(self) -> I32x4 {
+        I32x4(self.0)
+    }
+
+    // Swizzles
+
+    /// Permutes the lanes as `_mm_shuffle_ps` does: lane `i` of the result is lane
+    /// `(IMM8 >> (2 * i)) & 3` of this vector, so `shuffle::<0b00_01_10_11>()` is `wzyx()`.
+    /// `IMM8` must be in `0..256`; other values fail to compile.
+    #[inline]
+    pub fn shuffle<const IMM8: i32>(self) -> F32x4 {
+        const { assert!(IMM8 >= 0 && IMM8 < 256, "shuffle index out of range") };
+        // Select the four bytes of each source lane, since the lane indices aren't constants that
+        // `i32x4_shuffle` can take.
+        let bytes = |lane: i32| 0x0302_0100 + 0x0404_0404 * ((IMM8 >> (2 * lane)) & 3) as u32;
+        let indices = wasm32::u32x4(bytes(0), bytes(1), bytes(2), bytes(3));
+        F32x4(wasm32::i8x16_swizzle(self.0, indices))
+    }
+
+    // Extraction
+
+    #[inline]
+    pub fn xy(self) -> F32x2 {
+        F32x2(wasm32::u64x2_extract_lane::<0>(self.0))
+    }
+
+    #[inline]
+    pub fn xw(self) -> F32x2 {
+        self.xwyz().xy()
+    }
+
+    #[inline]
+    pub fn yx(self) -> F32x2 {
+        self.yxwz().xy()
+    }
+
+    #[inline]
+    pub fn zy(self) -> F32x2 {
+        self.zyxw().xy()
+    }
+
+    #[inline]
+    pub fn zw(self) -> F32x2 {
+        F32x2(wasm32::u64x2_extract_lane::<1>(self.0))
+    }
+
+    // Concatenations
+
+    #[inline]
+    pub fn concat_xy_xy(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::i64x2_shuffle::<0, 2>(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn concat_xy_zw(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::i64x2_shuffle::<0, 3>(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn concat_zw_zw(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::i64x2_shuffle::<1, 3>(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn concat_wz_yx(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::i32x4_shuffle::<3, 2, 5, 4>(self.0, other.0))
+    }
+}
+
+impl Default for F32x4 {
+    #[inline]
+    fn default() -> F32x4 {
+        F32x4(wasm32::f32x4_splat(0.0))
+    }
+}
+
+impl Index<usize> for F32x4 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, index: usize) -> &f32 {
+        unsafe { &mem::transmute::<&v128, &[f32; 4]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for F32x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        unsafe { &mut mem::transmute::<&mut v128, &mut [f32; 4]>(&mut self.0)[index] }
+    }
+}
+
+impl Debug for F32x4 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}, {}, {}>", self[0], self[1], self[2], self[3])
+    }
+}
+
+impl PartialEq for F32x4 {
+    #[inline]
+    fn eq(&self, other: &F32x4) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+impl Add<F32x4> for F32x4 {
+    type Output = F32x4;
+    #[inline]
+    fn add(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::f32x4_add(self.0, other.0))
+    }
+}
+
+impl Div<F32x4> for F32x4 {
+    type Output = F32x4;
+    #[inline]
+    fn div(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::f32x4_div(self.0, other.0))
+    }
+}
+
+impl Mul<F32x4> for F32x4 {
+    type Output = F32x4;
+    #[inline]
+    fn mul(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::f32x4_mul(self.0, other.0))
+    }
+}
+
+impl Sub<F32x4> for F32x4 {
+    type Output = F32x4;
+    #[inline]
+    fn sub(self, other: F32x4) -> F32x4 {
+        F32x4(wasm32::f32x4_sub(self.0, other.0))
+    }
+}
+
+// Two 32-bit signed integers
+
+#[derive(Clone, Copy)]
+pub struct I32x2(pub u64);
+
+impl I32x2 {
+    // Constructors
+
+    #[inline]
+    pub fn new(a: i32, b: i32) -> I32x2 {
+        I32x2(a as u32 as u64 | ((b as u32 as u64) << 32))
+    }
+
+    #[inline]
+    pub fn splat(x: i32) -> I32x2 {
+        I32x2::new(x, x)
+    }
+
+    // Accessors
+
+    #[inline]
+    pub fn x(self) -> i32 {
+        self[0]
+    }
+
+    #[inline]
+    pub fn y(self) -> i32 {
+        self[1]
+    }
+
+    // Concatenations
+
+    #[inline]
+    pub fn concat_xy_xy(self, other: I32x2) -> I32x4 {
+        I32x4(wasm32::u64x2(self.0, other.0))
+    }
+
+    // Conversions
+
+    #[inline]
+    pub fn to_i32x4(self) -> I32x4 {
+        I32x4(wasm32::u64x2(self.0, 0))
+    }
+
+    #[inline]
+    pub fn to_f32x4(self) -> F32x4 {
+        self.to_i32x4().to_f32x4()
+    }
+
+    /// Converts these packed integers to floats.
+    #[inline]
+    pub fn to_f32x2(self) -> F32x2 {
+        self.to_f32x4().xy()
+    }
+
+    // Basic operations
+
+    #[inline]
+    pub fn max(self, other: I32x2) -> I32x2 {
+        self.to_i32x4().max(other.to_i32x4()).xy()
+    }
+
+    #[inline]
+    pub fn min(self, other: I32x2) -> I32x2 {
+        self.to_i32x4().min(other.to_i32x4()).xy()
+    }
+
+    // Comparisons
+
+    // TODO(pcwalton): Use the `U32x2` type!
+    #[inline]
+    pub fn packed_eq(self, other: I32x2) -> U32x4 {
+        self.to_i32x4().packed_eq(other.to_i32x4())
+    }
+
+    #[inline]
+    pub fn packed_gt(self, other: I32x2) -> U32x4 {
+        self.to_i32x4().packed_gt(other.to_i32x4())
+    }
+
+    #[inline]
+    pub fn packed_le(self, other: I32x2) -> U32x4 {
+        self.to_i32x4().packed_le(other.to_i32x4())
+    }
+}
+
+impl Default for I32x2 {
+    #[inline]
+    fn default() -> I32x2 {
+        I32x2(0)
+    }
+}
+
+impl Index<usize> for I32x2 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &i32 {
+        unsafe { &mem::transmute::<&u64, &[i32; 2]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for I32x2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        unsafe { &mut mem::transmute::<&mut u64, &mut [i32; 2]>(&mut self.0)[index] }
+    }
+}
+
+impl Add<I32x2> for I32x2 {
+    type Output = I32x2;
+    #[inline]
+    fn add(self, other: I32x2) -> I32x2 {
+        (self.to_i32x4() + other.to_i32x4()).xy()
+    }
+}
+
+impl Sub<I32x2> for I32x2 {
+    type Output = I32x2;
+    #[inline]
+    fn sub(self, other: I32x2) -> I32x2 {
+        (self.to_i32x4() - other.to_i32x4()).xy()
+    }
+}
+
+impl Mul<I32x2> for I32x2 {
+    type Output = I32x2;
+    #[inline]
+    fn mul(self, other: I32x2) -> I32x2 {
+        (self.to_i32x4() * other.to_i32x4()).xy()
+    }
+}
+
+impl Debug for I32x2 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}>", self[0], self[1])
+    }
+}
+
+impl PartialEq for I32x2 {
+    #[inline]
+    fn eq(&self, other: &I32x2) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+// Four 32-bit signed integers
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I32x4(pub v128);
+
+impl I32x4 {
+    // Constructors
+
+    #[inline]
+    pub fn new(a: i32, b: i32, c: i32, d: i32) -> I32x4 {
+        I32x4(wasm32::i32x4(a, b, c, d))
+    }
+
+    #[inline]
+    pub fn splat(x: i32) -> I32x4 {
+        I32x4(wasm32::i32x4_splat(x))
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [i32; 4]) -> I32x4 {
+        I32x4::new(array[0], array[1], array[2], array[3])
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 4] {
+        unsafe { mem::transmute::<v128, [i32; 4]>(self.0) }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, even though WebAssembly loads themselves accept any address.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const i32) -> I32x4 {
+        I32x4(wasm32::v128_load(ptr as *const v128))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, even though WebAssembly stores themselves accept any address.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut i32) {
+        wasm32::v128_store(ptr as *mut v128, self.0)
+    }
+
+    // Extraction
+
+    #[inline]
+    pub fn xy(self) -> I32x2 {
+        I32x2(wasm32::u64x2_extract_lane::<0>(self.0))
+    }
+
+    #[inline]
+    pub fn xw(self) -> I32x2 {
+        self.xwyz().xy()
+    }
+
+    #[inline]
+    pub fn yx(self) -> I32x2 {
+        self.yxwz().xy()
+    }
+
+    #[inline]
+    pub fn zy(self) -> I32x2 {
+        self.zyxw().xy()
+    }
+
+    #[inline]
+    pub fn zw(self) -> I32x2 {
+        I32x2(wasm32::u64x2_extract_lane::<1>(self.0))
+    }
+
+    // Concatenations
+
+    #[inline]
+    pub fn concat_xy_xy(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i64x2_shuffle::<0, 2>(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn concat_zw_zw(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i64x2_shuffle::<1, 3>(self.0, other.0))
+    }
+
+    // Conversions
+
+    /// Converts these packed integers to floats.
+    #[inline]
+    pub fn to_f32x4(self) -> F32x4 {
+        F32x4(wasm32::f32x4_convert_i32x4(self.0))
+    }
+
+    /// Reinterprets the bits of these packed integers as floats.
+    #[inline]
+    pub fn to_f32x4_bits(self) -> F32x4 {
+        F32x4(self.0)
+    }
+
+    /// Converts these packed signed integers to unsigned integers.
+    ///
+    /// Overflowing values will wrap around.
+    #[inline]
+    pub fn to_u32x4(self) -> U32x4 {
+        U32x4(self.0)
+    }
+
+    /// Clamps each lane to `0..=255` and packs the lanes into the bytes of a `u32`, lane 0 in the
+    /// least significant byte. Stored little-endian, the word's bytes are in RGBA order.
+    #[inline]
+    pub fn to_rgba8(self) -> u32 {
+        // As on x86, narrow with signed saturation first, since the second narrowing reads its
+        // inputs as signed.
+        let words = wasm32::i16x8_narrow_i32x4(self.0, self.0);
+        wasm32::i32x4_extract_lane::<0>(wasm32::u8x16_narrow_i16x8(words, words)) as u32
+    }
+
+    /// Unpacks the bytes of a `u32` into the lanes, least significant byte in lane 0. This is the
+    /// inverse of `to_rgba8`.
+    #[inline]
+    pub fn from_u8_bytes(bytes: u32) -> I32x4 {
+        let words = wasm32::u16x8_extend_low_u8x16(wasm32::u32x4_splat(bytes));
+        I32x4(wasm32::u32x4_extend_low_u16x8(words))
+    }
+
+    // Basic operations
+
+    #[inline]
+    pub fn max(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i32x4_max(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn min(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i32x4_min(self.0, other.0))
+    }
+
+    /// Returns the absolute value of each lane. `i32::MIN` has no positive counterpart, so it
+    /// wraps around to itself, as with `i32::wrapping_abs`.
+    #[inline]
+    pub fn abs(self) -> I32x4 {
+        I32x4(wasm32::i32x4_abs(self.0))
+    }
+
+    /// Multiplies each pair of lanes and returns the high 32 bits of the 64-bit signed products,
+    /// as `((a as i64 * b as i64) >> 32) as i32` does. This scales each lane of `self` by the
+    /// fraction `other / 2³²`.
+    #[inline]
+    pub fn mulhi(self, other: I32x4) -> I32x4 {
+        let lo = wasm32::i64x2_extmul_low_i32x4(self.0, other.0);
+        let hi = wasm32::i64x2_extmul_high_i32x4(self.0, other.0);
+        I32x4(wasm32::i32x4_shuffle::<1, 3, 5, 7>(lo, hi))
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: I32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_eq(self.0, other.0))
+    }
+
+    // Comparisons
+
+    #[inline]
+    pub fn packed_gt(self, other: I32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_gt(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn packed_lt(self, other: I32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_lt(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn packed_le(self, other: I32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_le(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn packed_ge(self, other: I32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_ge(self.0, other.0))
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> I32x4 {
+        // `simd128` only shifts every lane by the same amount.
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+        I32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    /// Arithmetically shifts each lane right by the corresponding lane of `counts`, filling with
+    /// copies of the sign bit. Counts of 32 or more fill the whole lane with the sign bit.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> I32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i] >> counts[i].min(31);
+        I32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+}
+
+impl Default for I32x4 {
+    #[inline]
+    fn default() -> I32x4 {
+        I32x4(wasm32::i32x4_splat(0))
+    }
+}
+
+impl Index<usize> for I32x4 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &i32 {
+        unsafe { &mem::transmute::<&v128, &[i32; 4]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for I32x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        unsafe { &mut mem::transmute::<&mut v128, &mut [i32; 4]>(&mut self.0)[index] }
+    }
+}
+
+impl Add<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn add(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i32x4_add(self.0, other.0))
+    }
+}
+
+impl Sub<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn sub(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i32x4_sub(self.0, other.0))
+    }
+}
+
+impl Mul<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn mul(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::i32x4_mul(self.0, other.0))
+    }
+}
+
+// Division rounds toward zero, like `i32::wrapping_div`, and is exact over the whole `i32` range.
+// As on x86, dividing by zero produces `i32::MIN` rather than panicking.
+//
+// Each pair of lanes is divided as `f64`s, which is exact, and truncated back with saturation.
+// Saturation is wrong for `i32::MIN / -1` and for the divisions by zero, so those lanes are
+// patched afterward.
+impl Div<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn div(self, other: I32x4) -> I32x4 {
+        let divide = |a, b| {
+            let quotient = wasm32::f64x2_div(wasm32::f64x2_convert_low_i32x4(a),
+                                             wasm32::f64x2_convert_low_i32x4(b));
+            wasm32::i32x4_trunc_sat_f64x2_zero(quotient)
+        };
+        let lo = divide(self.0, other.0);
+        let hi = divide(wasm32::i64x2_shuffle::<1, 1>(self.0, self.0),
+                        wasm32::i64x2_shuffle::<1, 1>(other.0, other.0));
+        let quotient = wasm32::i64x2_shuffle::<0, 2>(lo, hi);
+        let overflow = self.packed_eq(I32x4::splat(i32::MIN)) &
+            other.packed_eq(I32x4::splat(-1));
+        let special = overflow | other.packed_eq(I32x4::default());
+        I32x4(wasm32::v128_bitselect(wasm32::i32x4_splat(i32::MIN), quotient, special.0))
+    }
+}
+
+impl BitAnd<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn bitand(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::v128_and(self.0, other.0))
+    }
+}
+
+impl BitOr<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn bitor(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::v128_or(self.0, other.0))
+    }
+}
+
+impl BitXor<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn bitxor(self, other: I32x4) -> I32x4 {
+        I32x4(wasm32::v128_xor(self.0, other.0))
+    }
+}
+
+impl Not for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn not(self) -> I32x4 {
+        I32x4(wasm32::v128_not(self.0))
+    }
+}
+
+impl Shl<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> I32x4 {
+        // `i32x4_shl` takes the amount modulo 32; match x86, which shifts everything out.
+        if amount >= 32 {
+            return I32x4::splat(0);
+        }
+        I32x4(wasm32::i32x4_shl(self.0, amount))
+    }
+}
+
+impl Shr<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shr(self, amount: u32) -> I32x4 {
+        // `i32x4_shr` takes the amount modulo 32; match x86, which fills the lane with the sign.
+        I32x4(wasm32::i32x4_shr(self.0, amount.min(31)))
+    }
+}
+
+impl Debug for I32x4 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}, {}, {}>", self[0], self[1], self[2], self[3])
+    }
+}
+
+impl PartialEq for I32x4 {
+    #[inline]
+    fn eq(&self, other: &I32x4) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+// Two 32-bit unsigned integers
+
+#[derive(Clone, Copy)]
+pub struct U32x2(pub u64);
+
+impl U32x2 {
+    #[inline]
+    pub fn new(x: u32, y: u32) -> U32x2 {
+        U32x2(x as u64 | ((y as u64) << 32))
+    }
+
+    #[inline]
+    pub fn splat(x: u32) -> U32x2 {
+        U32x2::new(x, x)
+    }
+
+    /// Returns true if both booleans in this vector are true.
+    ///
+    /// The result is *undefined* if both values in this vector are not booleans. A boolean is a
+    /// value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn all_true(self) -> bool {
+        self.0 == !0
+    }
+
+    /// Returns true if both booleans in this vector are false.
+    ///
+    /// The result is *undefined* if both values in this vector are not booleans. A boolean is a
+    /// value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn all_false(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub fn to_i32x2(self) -> I32x2 {
+        I32x2(self.0)
+    }
+}
+
+impl Not for U32x2 {
+    type Output = U32x2;
+    #[inline]
+    fn not(self) -> U32x2 {
+        U32x2(!self.0)
+    }
+}
+
+impl BitAnd<U32x2> for U32x2 {
+    type Output = U32x2;
+    #[inline]
+    fn bitand(self, other: U32x2) -> U32x2 {
+        U32x2(self.0 & other.0)
+    }
+}
+
+impl BitOr<U32x2> for U32x2 {
+    type Output = U32x2;
+    #[inline]
+    fn bitor(self, other: U32x2) -> U32x2 {
+        U32x2(self.0 | other.0)
+    }
+}
+
+// Four 32-bit unsigned integers
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U32x4(pub v128);
+
+impl U32x4 {
+    // Constructors
+
+    #[inline]
+    pub fn new(a: u32, b: u32, c: u32, d: u32) -> U32x4 {
+        U32x4(wasm32::u32x4(a, b, c, d))
+    }
+
+    #[inline]
+    pub fn splat(x: u32) -> U32x4 {
+        U32x4(wasm32::u32x4_splat(x))
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u32; 4]) -> U32x4 {
+        U32x4::new(array[0], array[1], array[2], array[3])
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 4] {
+        unsafe { mem::transmute::<v128, [u32; 4]>(self.0) }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, even though WebAssembly loads themselves accept any address.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const u32) -> U32x4 {
+        U32x4(wasm32::v128_load(ptr as *const v128))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, even though WebAssembly stores themselves accept any address.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut u32) {
+        wasm32::v128_store(ptr as *mut v128, self.0)
+    }
+
+    // Conversions
+
+    /// Converts these packed unsigned integers to signed integers.
+    ///
+    /// Overflowing values will wrap around.
+    #[inline]
+    pub fn to_i32x4(self) -> I32x4 {
+        I32x4(self.0)
+    }
+
+    // Basic operations
+
+    /// Returns true if all four booleans in this vector are true.
+    ///
+    /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn all_true(self) -> bool {
+        wasm32::i32x4_all_true(self.0)
+    }
+
+    /// Returns true if all four booleans in this vector are false.
+    ///
+    /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn all_false(self) -> bool {
+        !wasm32::v128_any_true(self.0)
+    }
+
+    /// Packs the high bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        wasm32::i32x4_bitmask(self.0) as u32
+    }
+
+    // Selection
+
+    /// Picks each lane from `a` where this mask is true and from `b` where it is false.
+    ///
+    /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+        // `v128.bitselect` selects bit by bit, which is the same thing for boolean lanes.
+        F32x4(wasm32::v128_bitselect(a.0, b.0, self.0))
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> U32x4 {
+        // `simd128` only shifts every lane by the same amount.
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+        U32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    /// Logically shifts each lane right by the corresponding lane of `counts`. Counts of 32 or
+    /// more produce zero.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> U32x4 {
+        let (values, counts) = (self.to_array(), counts.to_array());
+        let shift = |i: usize| values[i].checked_shr(counts[i]).unwrap_or(0);
+        U32x4::new(shift(0), shift(1), shift(2), shift(3))
+    }
+
+    // Extraction
+
+    #[inline]
+    pub fn xy(self) -> U32x2 {
+        U32x2(wasm32::u64x2_extract_lane::<0>(self.0))
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_eq(self.0, other.0))
+    }
+}
+
+impl Debug for U32x4 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}, {}, {}>", self[0], self[1], self[2], self[3])
+    }
+}
+
+impl Index<usize> for U32x4 {
+    type Output = u32;
+    #[inline]
+    fn index(&self, index: usize) -> &u32 {
+        unsafe { &mem::transmute::<&v128, &[u32; 4]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for U32x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u32 {
+        unsafe { &mut mem::transmute::<&mut v128, &mut [u32; 4]>(&mut self.0)[index] }
+    }
+}
+
+impl PartialEq for U32x4 {
+    #[inline]
+    fn eq(&self, other: &U32x4) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+// Unsigned arithmetic wraps on overflow, like `u32::wrapping_add` and friends.
+impl Add<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn add(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_add(self.0, other.0))
+    }
+}
+
+impl Sub<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn sub(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_sub(self.0, other.0))
+    }
+}
+
+impl Mul<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn mul(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::i32x4_mul(self.0, other.0))
+    }
+}
+
+impl Not for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn not(self) -> U32x4 {
+        U32x4(wasm32::v128_not(self.0))
+    }
+}
+
+impl BitAnd<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitand(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::v128_and(self.0, other.0))
+    }
+}
+
+impl BitOr<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitor(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::v128_or(self.0, other.0))
+    }
+}
+
+impl BitXor<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitxor(self, other: U32x4) -> U32x4 {
+        U32x4(wasm32::v128_xor(self.0, other.0))
+    }
+}
+
+impl Shl<u32> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> U32x4 {
+        // `i32x4_shl` takes the amount modulo 32; match x86, which shifts everything out.
+        if amount >= 32 {
+            return U32x4::splat(0);
+        }
+        U32x4(wasm32::i32x4_shl(self.0, amount))
+    }
+}
+
+impl Shr<u32> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn shr(self, amount: u32) -> U32x4 {
+        // `u32x4_shr` takes the amount modulo 32; match x86, which shifts everything out.
+        if amount >= 32 {
+            return U32x4::splat(0);
+        }
+        U32x4(wasm32::u32x4_shr(self.0, amount))
+    }
+}
+
+// Sixteen 8-bit unsigned integers
+
+#[derive(Clone, Copy)]
+pub struct U8x16(pub v128);
+
+impl U8x16 {
+    // Constructors
+
+    #[inline]
+    pub fn splat(x: u8) -> U8x16 {
+        U8x16(wasm32::u8x16_splat(x))
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u8; 16]) -> U8x16 {
+        unsafe { U8x16(mem::transmute::<[u8; 16], v128>(array)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u8; 16] {
+        unsafe { mem::transmute::<v128, [u8; 16]>(self.0) }
+    }
+
+    // Conversions
+
+    /// Reinterprets these bytes as four packed 32-bit signed integers.
+    #[inline]
+    pub fn as_i32x4(self) -> I32x4 {
+        I32x4(self.0)
+    }
+
+    /// Clamps each lane of `a`, `b`, `c`, and `d` to `0..=255` and packs them, in that order, into
+    /// the sixteen bytes: `a[0]` lands in byte 0 and `d[3]` in byte 15. This is `I32x4::to_rgba8`
+    /// for four vectors at once.
+    #[inline]
+    pub fn pack_i32x4s(a: I32x4, b: I32x4, c: I32x4, d: I32x4) -> U8x16 {
+        let lo = wasm32::i16x8_narrow_i32x4(a.0, b.0);
+        let hi = wasm32::i16x8_narrow_i32x4(c.0, d.0);
+        U8x16(wasm32::u8x16_narrow_i16x8(lo, hi))
+    }
+
+    // Basic operations
+
+    /// Returns a vector whose byte `i` is byte `indices[i] & 15` of this vector, or zero if the
+    /// high bit of `indices[i]` is set.
+    #[inline]
+    pub fn shuffle(self, indices: U8x16) -> U8x16 {
+        // `i8x16.swizzle` zeroes every byte whose index is 16 or more, so clear the bits between
+        // the high bit and the low four to get the `pshufb` behavior of the other backends.
+        let indices = wasm32::v128_and(indices.0, wasm32::u8x16_splat(0x8f));
+        U8x16(wasm32::i8x16_swizzle(self.0, indices))
+    }
+
+    /// Packs the high bit of each byte into the low sixteen bits of an integer, byte 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        wasm32::u8x16_bitmask(self.0) as u32
+    }
+
+    /// Averages each pair of bytes, rounding halves up: `(a + b + 1) >> 1`.
+    #[inline]
+    pub fn avg(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::u8x16_avgr(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn min(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::u8x16_min(self.0, other.0))
+    }
+
+    #[inline]
+    pub fn max(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::u8x16_max(self.0, other.0))
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::i8x16_eq(self.0, other.0))
+    }
+
+    // Filtering
+
+    /// Bilinearly interpolates, byte by byte, between the texels `tl`, `tr`, `bl`, and `br` at the
+    /// top left, top right, bottom left, and bottom right of a square. With RGBA8 data this
+    /// filters four pixels at once.
+    ///
+    /// The weights are fixed-point fractions with 8 fractional bits: 0 selects the left or top
+    /// texels, 256 selects the right or bottom ones, and 128 is halfway between. Weights above 256
+    /// are treated as 256. Each byte is interpolated horizontally and then vertically in 16-bit
+    /// lanes, rounding to nearest after each step.
+    #[inline]
+    pub fn bilinear_blend(tl: U8x16, tr: U8x16, bl: U8x16, br: U8x16, wx: u16, wy: u16)
+                          -> U8x16 {
+        let (wx, wy) = (wx.min(256), wy.min(256));
+        tl.lerp_fixed(tr, wx).lerp_fixed(bl.lerp_fixed(br, wx), wy)
+    }
+
+    // Computes `(self × (256 - weight) + other × weight + 128) >> 8` for each byte. `weight` must
+    // be at most 256.
+    #[inline]
+    fn lerp_fixed(self, other: U8x16, weight: u16) -> U8x16 {
+        let self_weight = wasm32::u16x8_splat(256 - weight);
+        let other_weight = wasm32::u16x8_splat(weight);
+        let round = wasm32::u16x8_splat(128);
+        // Both products fit in 16 bits, as does their sum, since the weights add up to 256.
+        let lerp = |a, b| {
+            let sum = wasm32::i16x8_add(wasm32::i16x8_mul(a, self_weight),
+                                        wasm32::i16x8_mul(b, other_weight));
+            wasm32::u16x8_shr(wasm32::i16x8_add(sum, round), 8)
+        };
+        let lo = lerp(wasm32::u16x8_extend_low_u8x16(self.0),
+                      wasm32::u16x8_extend_low_u8x16(other.0));
+        let hi = lerp(wasm32::u16x8_extend_high_u8x16(self.0),
+                      wasm32::u16x8_extend_high_u8x16(other.0));
+        U8x16(wasm32::u8x16_narrow_i16x8(lo, hi))
+    }
+}
+
+impl Debug for U8x16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let bytes = self.to_array();
+        write!(f, "<{}", bytes[0])?;
+        for byte in &bytes[1..] {
+            write!(f, ", {}", byte)?;
+        }
+        write!(f, ">")
+    }
+}
+
+impl Index<usize> for U8x16 {
+    type Output = u8;
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        unsafe { &mem::transmute::<&v128, &[u8; 16]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for U8x16 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        unsafe { &mut mem::transmute::<&mut v128, &mut [u8; 16]>(&mut self.0)[index] }
+    }
+}
+
+impl PartialEq for U8x16 {
+    #[inline]
+    fn eq(&self, other: &U8x16) -> bool {
+        self.packed_eq(*other).movemask() == 0xffff
+    }
+}
+
+// Byte arithmetic saturates instead of wrapping, since that is what pixel and coverage math wants.
+impl Add<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn add(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::u8x16_add_sat(self.0, other.0))
+    }
+}
+
+impl Sub<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn sub(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::u8x16_sub_sat(self.0, other.0))
+    }
+}
+
+impl Not for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn not(self) -> U8x16 {
+        U8x16(wasm32::v128_not(self.0))
+    }
+}
+
+impl BitAnd<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitand(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::v128_and(self.0, other.0))
+    }
+}
+
+impl BitOr<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitor(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::v128_or(self.0, other.0))
+    }
+}
+
+impl BitXor<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitxor(self, other: U8x16) -> U8x16 {
+        U8x16(wasm32::v128_xor(self.0, other.0))
+    }
+}
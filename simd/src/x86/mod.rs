@@ -11,7 +11,7 @@
 use std::cmp::PartialEq;
 use std::fmt::{self, Debug, Formatter};
 use std::mem;
-use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Not, Shr, Sub};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Not, Shl, Shr, Sub};
 
 #[cfg(target_pointer_width = "32")]
 use std::arch::x86::{__m128, __m128i};
@@ -218,6 +218,7 @@ impl Sub<F32x2> for F32x2 {
 // Four 32-bit floats
 
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct F32x4(pub __m128);
 
 impl F32x4 {
@@ -236,6 +237,72 @@ impl F32x4 {
         unsafe { F32x4(x86::_mm_set1_ps(x)) }
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [f32; 4]) -> F32x4 {
+        unsafe { F32x4(x86::_mm_loadu_ps(array.as_ptr())) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] {
+        unsafe {
+            let mut array = [0.0; 4];
+            x86::_mm_storeu_ps(array.as_mut_ptr(), self.0);
+            array
+        }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, and in practice faults.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const f32) -> F32x4 {
+        F32x4(x86::_mm_load_ps(ptr))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, and in practice faults.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut f32) {
+        x86::_mm_store_ps(ptr, self.0)
+    }
+
+    /// Loads the float at byte address `base + indices[i] * stride_bytes` into each lane `i`.
+    ///
+    /// With AVX2 enabled, strides of 1, 2, 4, or 8 bytes use a hardware gather; other strides
+    /// load one lane at a time.
+    ///
+    /// # Safety
+    ///
+    /// Every computed address must be valid for an unaligned read of an `f32`.
+    #[inline]
+    pub unsafe fn gather_stride(base: *const u8, indices: I32x4, stride_bytes: i32) -> F32x4 {
+        #[cfg(target_feature = "avx2")]
+        {
+            let ptr = base as *const f32;
+            match stride_bytes {
+                1 => return F32x4(x86::_mm_i32gather_ps::<1>(ptr, indices.0)),
+                2 => return F32x4(x86::_mm_i32gather_ps::<2>(ptr, indices.0)),
+                4 => return F32x4(x86::_mm_i32gather_ps::<4>(ptr, indices.0)),
+                8 => return F32x4(x86::_mm_i32gather_ps::<8>(ptr, indices.0)),
+                _ => {}
+            }
+        }
+        let load = |i: usize| {
+            let offset = indices[i] as isize * stride_bytes as isize;
+            (base.offset(offset) as *const f32).read_unaligned()
+        };
+        F32x4::new(load(0), load(1), load(2), load(3))
+    }
+
     // Basic operations
 
     #[inline]
@@ -243,6 +310,34 @@ impl F32x4 {
         unsafe { F32x4(x86::_mm_rcp_ps(self.0)) }
     }
 
+    #[inline]
+    pub fn approx_rsqrt(self) -> F32x4 {
+        unsafe { F32x4(x86::_mm_rsqrt_ps(self.0)) }
+    }
+
+    /// Computes `self * b + c`.
+    ///
+    /// With the `fma` target feature enabled this is a single fused multiply-add, rounded once.
+    /// Otherwise the product is rounded before the addition.
+    #[inline]
+    pub fn mul_add(self, b: F32x4, c: F32x4) -> F32x4 {
+        #[cfg(target_feature = "fma")]
+        unsafe {
+            F32x4(x86::_mm_fmadd_ps(self.0, b.0, c.0))
+        }
+        #[cfg(not(target_feature = "fma"))]
+        {
+            self * b + c
+        }
+    }
+
+    /// Returns the dot product of all four lanes of this vector and `other`.
+    #[inline]
+    pub fn dot(self, other: F32x4) -> f32 {
+        // `dpps` is SSE4.1, like `floor` and `ceil` above.
+        unsafe { x86::_mm_cvtss_f32(x86::_mm_dp_ps::<0xff>(self.0, other.0)) }
+    }
+
     #[inline]
     pub fn min(self, other: F32x4) -> F32x4 {
         unsafe { F32x4(x86::_mm_min_ps(self.0, other.0)) }
@@ -253,6 +348,10 @@ impl F32x4 {
         unsafe { F32x4(x86::_mm_max_ps(self.0, other.0)) }
     }
 
+    /// Clamps each lane to the range `[min, max]`.
+    ///
+    /// `maxps` and `minps` return their second operand when either is NaN, so a NaN lane in
+    /// `self` becomes `min`. The result for NaN bounds differs between backends.
     #[inline]
     pub fn clamp(self, min: F32x4, max: F32x4) -> F32x4 {
         self.max(min).min(max)
@@ -260,22 +359,64 @@ impl F32x4 {
 
     #[inline]
     pub fn abs(self) -> F32x4 {
-        unsafe {
-            let tmp = x86::_mm_srli_epi32(I32x4::splat(-1).0, 1);
-            F32x4(x86::_mm_and_ps(x86::_mm_castsi128_ps(tmp), self.0))
-        }
+        // -0.0 has only the sign bit set, so this clears the sign bit and nothing else.
+        unsafe { F32x4(x86::_mm_andnot_ps(x86::_mm_set1_ps(-0.0), self.0)) }
     }
 
+    /// Rounds each lane down to an integer.
+    ///
+    /// This uses `roundps`, which requires SSE4.1 even when the crate is built for a target
+    /// without it. Binaries for generic x86 targets should check `detect::has_sse41` first or
+    /// call `floor_sse2` instead.
     #[inline]
     pub fn floor(self) -> F32x4 {
         unsafe { F32x4(x86::_mm_floor_ps(self.0)) }
     }
 
+    /// Rounds each lane down to an integer using SSE2 instructions only. The result is the same
+    /// as that of `floor`, including for negative zero, infinities, and NaNs.
+    #[inline]
+    pub fn floor_sse2(self) -> F32x4 {
+        unsafe {
+            let sign = x86::_mm_set1_ps(-0.0);
+            let truncated = x86::_mm_cvtepi32_ps(x86::_mm_cvttps_epi32(self.0));
+            // Truncation rounds negative non-integers up, so step those down by one. Or-ing in
+            // the sign of the input keeps `-0.0` negative.
+            let rounded_up = x86::_mm_cmpgt_ps(truncated, self.0);
+            let floored = x86::_mm_sub_ps(truncated,
+                                          x86::_mm_and_ps(rounded_up, x86::_mm_set1_ps(1.0)));
+            let floored = x86::_mm_or_ps(floored, x86::_mm_and_ps(self.0, sign));
+            // Magnitudes of 2²³ or more are integers already, and may not fit in an `i32`. NaNs
+            // fail the comparison, so they pass through too.
+            let magnitude = x86::_mm_andnot_ps(sign, self.0);
+            let small = x86::_mm_cmplt_ps(magnitude, x86::_mm_set1_ps(8388608.0));
+            F32x4(x86::_mm_or_ps(x86::_mm_and_ps(small, floored),
+                                 x86::_mm_andnot_ps(small, self.0)))
+        }
+    }
+
+    /// Rounds each lane up to an integer.
+    ///
+    /// Like `floor`, this requires SSE4.1.
     #[inline]
     pub fn ceil(self) -> F32x4 {
         unsafe { F32x4(x86::_mm_ceil_ps(self.0)) }
     }
 
+    /// Rounds each lane to the nearest integer, with ties going to the even integer, so `2.5`
+    /// rounds to `2.0` and `3.5` rounds to `4.0`.
+    ///
+    /// Like `floor`, this requires SSE4.1.
+    #[inline]
+    pub fn round(self) -> F32x4 {
+        unsafe {
+            const MODE: i32 = x86::_MM_FROUND_TO_NEAREST_INT | x86::_MM_FROUND_NO_EXC;
+            F32x4(x86::_mm_round_ps::<MODE>(self.0))
+        }
+    }
+
+    /// Returns the square root of each lane. Negative lanes become NaN, and NaN lanes pass
+    /// through.
     #[inline]
     pub fn sqrt(self) -> F32x4 {
         unsafe { F32x4(x86::_mm_sqrt_ps(self.0)) }
@@ -311,6 +452,12 @@ impl F32x4 {
         !self.packed_gt(other)
     }
 
+    /// Packs the sign bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        unsafe { x86::_mm_movemask_ps(self.0) as u32 }
+    }
+
     // Conversions
 
     /// Converts these packed floats to integers via rounding.
@@ -319,6 +466,39 @@ impl F32x4 {
         unsafe { I32x4(x86::_mm_cvtps_epi32(self.0)) }
     }
 
+    /// Converts these packed floats to integers by truncating toward zero, with the same
+    /// saturating semantics as `as i32`: out-of-range lanes clamp to `i32::MIN` or `i32::MAX`, and
+    /// NaN lanes become 0.
+    #[inline]
+    pub fn to_i32x4_saturating(self) -> I32x4 {
+        unsafe {
+            // `cvttps2dq` returns `i32::MIN` for every out-of-range lane, so flip the bits of the
+            // ones that overflowed upward and clear the NaNs.
+            let truncated = x86::_mm_cvttps_epi32(self.0);
+            let limit = x86::_mm_set1_ps(2147483648.0);
+            let too_big = x86::_mm_castps_si128(x86::_mm_cmpge_ps(self.0, limit));
+            let ordered = x86::_mm_castps_si128(x86::_mm_cmpord_ps(self.0, self.0));
+            I32x4(x86::_mm_and_si128(x86::_mm_xor_si128(truncated, too_big), ordered))
+        }
+    }
+
+    /// Reinterprets the bits of these packed floats as integers.
+    #[inline]
+    pub fn to_i32x4_bits(self) -> I32x4 {
+        unsafe { I32x4(x86::_mm_castps_si128(self.0)) }
+    }
+
+    // Swizzles
+
+    /// Permutes the lanes as `_mm_shuffle_ps` does: lane `i` of the result is lane
+    /// `(IMM8 >> (2 * i)) & 3` of this vector, so `shuffle::<0b00_01_10_11>()` is `wzyx()`.
+    /// `IMM8` must be in `0..256`; other values fail to compile.
+    #[inline]
+    pub fn shuffle<const IMM8: i32>(self) -> F32x4 {
+        const { assert!(IMM8 >= 0 && IMM8 < 256, "shuffle index out of range") };
+        unsafe { F32x4(x86::_mm_shuffle_ps::<IMM8>(self.0, self.0)) }
+    }
+
     // Extraction
 
     #[inline]
@@ -612,6 +792,7 @@ impl PartialEq for I32x2 {
 // Four 32-bit signed integers
 
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct I32x4(pub __m128i);
 
 impl I32x4 {
@@ -630,6 +811,44 @@ impl I32x4 {
         unsafe { I32x4(x86::_mm_set1_epi32(x)) }
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [i32; 4]) -> I32x4 {
+        unsafe { I32x4(x86::_mm_loadu_si128(array.as_ptr() as *const __m128i)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 4] {
+        unsafe {
+            let mut array = [0; 4];
+            x86::_mm_storeu_si128(array.as_mut_ptr() as *mut __m128i, self.0);
+            array
+        }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, and in practice faults.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const i32) -> I32x4 {
+        I32x4(x86::_mm_load_si128(ptr as *const __m128i))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, and in practice faults.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut i32) {
+        x86::_mm_store_si128(ptr as *mut __m128i, self.0)
+    }
+
     // Extraction
 
     #[inline]
@@ -690,6 +909,12 @@ impl I32x4 {
         unsafe { F32x4(x86::_mm_cvtepi32_ps(self.0)) }
     }
 
+    /// Reinterprets the bits of these packed integers as floats.
+    #[inline]
+    pub fn to_f32x4_bits(self) -> F32x4 {
+        unsafe { F32x4(x86::_mm_castsi128_ps(self.0)) }
+    }
+
     /// Converts these packed signed integers to unsigned integers.
     ///
     /// Overflowing values will wrap around.
@@ -698,16 +923,102 @@ impl I32x4 {
         U32x4(self.0)
     }
 
+    /// Clamps each lane to `0..=255` and packs the lanes into the bytes of a `u32`, lane 0 in the
+    /// least significant byte. Stored little-endian, the word's bytes are in RGBA order.
+    #[inline]
+    pub fn to_rgba8(self) -> u32 {
+        // Pack with signed saturation first: `_mm_packus_epi16` reads its inputs as signed, so
+        // unsigned 16-bit values above 0x7fff would clamp to 0 rather than 255.
+        unsafe {
+            let words = x86::_mm_packs_epi32(self.0, self.0);
+            x86::_mm_cvtsi128_si32(x86::_mm_packus_epi16(words, words)) as u32
+        }
+    }
+
+    /// Unpacks the bytes of a `u32` into the lanes, least significant byte in lane 0. This is the
+    /// inverse of `to_rgba8`.
+    ///
+    /// This uses `pmovzxbd`, which requires SSE4.1; see `floor`.
+    #[inline]
+    pub fn from_u8_bytes(bytes: u32) -> I32x4 {
+        unsafe { I32x4(x86::_mm_cvtepu8_epi32(x86::_mm_cvtsi32_si128(bytes as i32))) }
+    }
+
     // Basic operations
 
     #[inline]
     pub fn max(self, other: I32x4) -> I32x4 {
-        unsafe { I32x4(x86::_mm_max_epi32(self.0, other.0)) }
+        #[cfg(target_feature = "sse4.1")]
+        unsafe {
+            I32x4(x86::_mm_max_epi32(self.0, other.0))
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        unsafe {
+            let greater = x86::_mm_cmpgt_epi32(self.0, other.0);
+            I32x4(x86::_mm_or_si128(x86::_mm_and_si128(greater, self.0),
+                                    x86::_mm_andnot_si128(greater, other.0)))
+        }
     }
 
     #[inline]
     pub fn min(self, other: I32x4) -> I32x4 {
-        unsafe { I32x4(x86::_mm_min_epi32(self.0, other.0)) }
+        #[cfg(target_feature = "sse4.1")]
+        unsafe {
+            I32x4(x86::_mm_min_epi32(self.0, other.0))
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        unsafe {
+            let greater = x86::_mm_cmpgt_epi32(self.0, other.0);
+            I32x4(x86::_mm_or_si128(x86::_mm_and_si128(greater, other.0),
+                                    x86::_mm_andnot_si128(greater, self.0)))
+        }
+    }
+
+    /// Returns the absolute value of each lane. `i32::MIN` has no positive counterpart, so it
+    /// wraps around to itself, as with `i32::wrapping_abs`.
+    #[inline]
+    pub fn abs(self) -> I32x4 {
+        #[cfg(target_feature = "ssse3")]
+        unsafe {
+            I32x4(x86::_mm_abs_epi32(self.0))
+        }
+        #[cfg(not(target_feature = "ssse3"))]
+        unsafe {
+            // Flip the bits of the negative lanes and add one, which is two's complement negation.
+            let sign = x86::_mm_srai_epi32::<31>(self.0);
+            I32x4(x86::_mm_sub_epi32(x86::_mm_xor_si128(self.0, sign), sign))
+        }
+    }
+
+    /// Multiplies each pair of lanes and returns the high 32 bits of the 64-bit signed products,
+    /// as `((a as i64 * b as i64) >> 32) as i32` does. This scales each lane of `self` by the
+    /// fraction `other / 2³²`.
+    #[inline]
+    pub fn mulhi(self, other: I32x4) -> I32x4 {
+        #[cfg(target_feature = "sse4.1")]
+        unsafe {
+            // `pmuldq` multiplies lanes 0 and 2 into 64-bit products, so shift lanes 1 and 3 down
+            // to multiply those too, then gather the high halves.
+            let even = x86::_mm_mul_epi32(self.0, other.0);
+            let odd = x86::_mm_mul_epi32(x86::_mm_srli_epi64::<32>(self.0),
+                                         x86::_mm_srli_epi64::<32>(other.0));
+            I32x4(x86::_mm_blend_epi16::<0xcc>(x86::_mm_srli_epi64::<32>(even), odd))
+        }
+        #[cfg(not(target_feature = "sse4.1"))]
+        unsafe {
+            // Take the high halves of the unsigned products from `pmuludq`, then correct them:
+            // reading a negative lane as unsigned adds 2³² to it, which adds the other factor to
+            // the high half.
+            let even = x86::_mm_mul_epu32(self.0, other.0);
+            let odd = x86::_mm_mul_epu32(x86::_mm_srli_epi64::<32>(self.0),
+                                         x86::_mm_srli_epi64::<32>(other.0));
+            let high_mask = x86::_mm_set1_epi64x(!0xffff_ffff);
+            let high = x86::_mm_or_si128(x86::_mm_srli_epi64::<32>(even),
+                                         x86::_mm_and_si128(odd, high_mask));
+            let self_fix = x86::_mm_and_si128(x86::_mm_srai_epi32::<31>(self.0), other.0);
+            let other_fix = x86::_mm_and_si128(x86::_mm_srai_epi32::<31>(other.0), self.0);
+            I32x4(x86::_mm_sub_epi32(x86::_mm_sub_epi32(high, self_fix), other_fix))
+        }
     }
 
     // Packed comparisons
@@ -733,6 +1044,45 @@ impl I32x4 {
     pub fn packed_le(self, other: I32x4) -> U32x4 {
         !self.packed_gt(other)
     }
+
+    #[inline]
+    pub fn packed_ge(self, other: I32x4) -> U32x4 {
+        !other.packed_gt(self)
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> I32x4 {
+        #[cfg(target_feature = "avx2")]
+        unsafe {
+            I32x4(x86::_mm_sllv_epi32(self.0, counts.0))
+        }
+        #[cfg(not(target_feature = "avx2"))]
+        {
+            let (values, counts) = (self.to_array(), counts.to_array());
+            let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+            I32x4::new(shift(0), shift(1), shift(2), shift(3))
+        }
+    }
+
+    /// Arithmetically shifts each lane right by the corresponding lane of `counts`, filling with
+    /// copies of the sign bit. Counts of 32 or more fill the whole lane with the sign bit.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> I32x4 {
+        #[cfg(target_feature = "avx2")]
+        unsafe {
+            I32x4(x86::_mm_srav_epi32(self.0, counts.0))
+        }
+        #[cfg(not(target_feature = "avx2"))]
+        {
+            let (values, counts) = (self.to_array(), counts.to_array());
+            let shift = |i: usize| values[i] >> counts[i].min(31);
+            I32x4::new(shift(0), shift(1), shift(2), shift(3))
+        }
+    }
 }
 
 impl Default for I32x4 {
@@ -781,6 +1131,28 @@ impl Mul<I32x4> for I32x4 {
     }
 }
 
+// Division rounds toward zero, like `i32::wrapping_div`, and is exact over the whole `i32` range.
+// Dividing by zero produces `i32::MIN` rather than panicking.
+//
+// Every `i32` converts exactly to an `f64`, and the truncated `f64` quotient of two of them is
+// always the exact integer quotient, so this costs two conversions and one `divpd` per pair of
+// lanes.
+impl Div<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn div(self, other: I32x4) -> I32x4 {
+        unsafe {
+            let (a_hi, b_hi) = (
+                x86::_mm_unpackhi_epi64(self.0, self.0),
+                x86::_mm_unpackhi_epi64(other.0, other.0),
+            );
+            let lo = x86::_mm_div_pd(x86::_mm_cvtepi32_pd(self.0), x86::_mm_cvtepi32_pd(other.0));
+            let hi = x86::_mm_div_pd(x86::_mm_cvtepi32_pd(a_hi), x86::_mm_cvtepi32_pd(b_hi));
+            I32x4(x86::_mm_unpacklo_epi64(x86::_mm_cvttpd_epi32(lo), x86::_mm_cvttpd_epi32(hi)))
+        }
+    }
+}
+
 impl BitAnd<I32x4> for I32x4 {
     type Output = I32x4;
     #[inline]
@@ -797,6 +1169,41 @@ impl BitOr<I32x4> for I32x4 {
     }
 }
 
+impl BitXor<I32x4> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn bitxor(self, other: I32x4) -> I32x4 {
+        unsafe { I32x4(x86::_mm_xor_si128(self.0, other.0)) }
+    }
+}
+
+impl Not for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn not(self) -> I32x4 {
+        self ^ I32x4::splat(!0)
+    }
+}
+
+impl Shl<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> I32x4 {
+        unsafe { I32x4(x86::_mm_sll_epi32(self.0, U32x4::new(amount, 0, 0, 0).0)) }
+    }
+}
+
+impl Shr<u32> for I32x4 {
+    type Output = I32x4;
+    #[inline]
+    fn shr(self, amount: u32) -> I32x4 {
+        // `psrad` already fills the lane with the sign bit for larger amounts; clamp anyway so
+        // every backend agrees by construction.
+        let amount = amount.min(31);
+        unsafe { I32x4(x86::_mm_sra_epi32(self.0, U32x4::new(amount, 0, 0, 0).0)) }
+    }
+}
+
 impl Debug for I32x4 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
@@ -878,6 +1285,7 @@ impl BitOr<U32x2> for U32x2 {
 // Four 32-bit unsigned integers
 
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct U32x4(pub __m128i);
 
 impl U32x4 {
@@ -896,6 +1304,44 @@ impl U32x4 {
         unsafe { U32x4(x86::_mm_set1_epi32(x as i32)) }
     }
 
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u32; 4]) -> U32x4 {
+        unsafe { U32x4(x86::_mm_loadu_si128(array.as_ptr() as *const __m128i)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 4] {
+        unsafe {
+            let mut array = [0; 4];
+            x86::_mm_storeu_si128(array.as_mut_ptr() as *mut __m128i, self.0);
+            array
+        }
+    }
+
+    /// Loads four lanes from 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, and in practice faults.
+    #[inline]
+    pub unsafe fn load_aligned(ptr: *const u32) -> U32x4 {
+        U32x4(x86::_mm_load_si128(ptr as *const __m128i))
+    }
+
+    /// Stores four lanes to 16-byte-aligned memory.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of four values and aligned to 16 bytes. Passing an unaligned
+    /// pointer is undefined behavior, and in practice faults.
+    #[inline]
+    pub unsafe fn store_aligned(self, ptr: *mut u32) {
+        x86::_mm_store_si128(ptr as *mut __m128i, self.0)
+    }
+
     // Conversions
 
     /// Converts these packed unsigned integers to signed integers.
@@ -926,6 +1372,59 @@ impl U32x4 {
         unsafe { x86::_mm_movemask_ps(x86::_mm_castsi128_ps(self.0)) == 0x00 }
     }
 
+    /// Packs the high bit of each lane into the low four bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        unsafe { x86::_mm_movemask_ps(x86::_mm_castsi128_ps(self.0)) as u32 }
+    }
+
+    // Selection
+
+    /// Picks each lane from `a` where this mask is true and from `b` where it is false.
+    ///
+    /// The result is *undefined* if all four values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn select(self, a: F32x4, b: F32x4) -> F32x4 {
+        // `blendvps` only looks at the sign bit of each mask lane. SSE4.1 is assumed, as for
+        // `floor` and `ceil`.
+        unsafe { F32x4(x86::_mm_blendv_ps(b.0, a.0, x86::_mm_castsi128_ps(self.0))) }
+    }
+
+    // Shifts
+
+    /// Shifts each lane left by the corresponding lane of `counts`. Counts of 32 or more produce
+    /// zero.
+    #[inline]
+    pub fn shl_each(self, counts: U32x4) -> U32x4 {
+        #[cfg(target_feature = "avx2")]
+        unsafe {
+            U32x4(x86::_mm_sllv_epi32(self.0, counts.0))
+        }
+        #[cfg(not(target_feature = "avx2"))]
+        {
+            let (values, counts) = (self.to_array(), counts.to_array());
+            let shift = |i: usize| values[i].checked_shl(counts[i]).unwrap_or(0);
+            U32x4::new(shift(0), shift(1), shift(2), shift(3))
+        }
+    }
+
+    /// Logically shifts each lane right by the corresponding lane of `counts`. Counts of 32 or
+    /// more produce zero.
+    #[inline]
+    pub fn shr_each(self, counts: U32x4) -> U32x4 {
+        #[cfg(target_feature = "avx2")]
+        unsafe {
+            U32x4(x86::_mm_srlv_epi32(self.0, counts.0))
+        }
+        #[cfg(not(target_feature = "avx2"))]
+        {
+            let (values, counts) = (self.to_array(), counts.to_array());
+            let shift = |i: usize| values[i].checked_shr(counts[i]).unwrap_or(0);
+            U32x4::new(shift(0), shift(1), shift(2), shift(3))
+        }
+    }
+
     // Extraction
 
     #[inline]
@@ -959,6 +1458,13 @@ impl Index<usize> for U32x4 {
     }
 }
 
+impl IndexMut<usize> for U32x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u32 {
+        unsafe { &mut mem::transmute::<&mut __m128i, &mut [u32; 4]>(&mut self.0)[index] }
+    }
+}
+
 impl PartialEq for U32x4 {
     #[inline]
     fn eq(&self, other: &U32x4) -> bool {
@@ -966,6 +1472,31 @@ impl PartialEq for U32x4 {
     }
 }
 
+// Unsigned arithmetic wraps on overflow, like `u32::wrapping_add` and friends.
+impl Add<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn add(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(x86::_mm_add_epi32(self.0, other.0)) }
+    }
+}
+
+impl Sub<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn sub(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(x86::_mm_sub_epi32(self.0, other.0)) }
+    }
+}
+
+impl Mul<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn mul(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(x86::_mm_mullo_epi32(self.0, other.0)) }
+    }
+}
+
 impl Not for U32x4 {
     type Output = U32x4;
     #[inline]
@@ -974,6 +1505,22 @@ impl Not for U32x4 {
     }
 }
 
+impl BitAnd<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitand(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(x86::_mm_and_si128(self.0, other.0)) }
+    }
+}
+
+impl BitOr<U32x4> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn bitor(self, other: U32x4) -> U32x4 {
+        unsafe { U32x4(x86::_mm_or_si128(self.0, other.0)) }
+    }
+}
+
 impl BitXor<U32x4> for U32x4 {
     type Output = U32x4;
     #[inline]
@@ -982,6 +1529,14 @@ impl BitXor<U32x4> for U32x4 {
     }
 }
 
+impl Shl<u32> for U32x4 {
+    type Output = U32x4;
+    #[inline]
+    fn shl(self, amount: u32) -> U32x4 {
+        unsafe { U32x4(x86::_mm_sll_epi32(self.0, U32x4::new(amount, 0, 0, 0).0)) }
+    }
+}
+
 impl Shr<u32> for U32x4 {
     type Output = U32x4;
     #[inline]
@@ -989,3 +1544,213 @@ impl Shr<u32> for U32x4 {
         unsafe { U32x4(x86::_mm_srl_epi32(self.0, U32x4::new(amount, 0, 0, 0).0)) }
     }
 }
+
+// Sixteen 8-bit unsigned integers
+
+#[derive(Clone, Copy)]
+pub struct U8x16(pub __m128i);
+
+impl U8x16 {
+    // Constructors
+
+    #[inline]
+    pub fn splat(x: u8) -> U8x16 {
+        unsafe { U8x16(x86::_mm_set1_epi8(x as i8)) }
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [u8; 16]) -> U8x16 {
+        unsafe { U8x16(x86::_mm_loadu_si128(array.as_ptr() as *const __m128i)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [u8; 16] {
+        let mut array = [0; 16];
+        unsafe { x86::_mm_storeu_si128(array.as_mut_ptr() as *mut __m128i, self.0) }
+        array
+    }
+
+    // Conversions
+
+    /// Reinterprets these bytes as four packed 32-bit signed integers.
+    #[inline]
+    pub fn as_i32x4(self) -> I32x4 {
+        I32x4(self.0)
+    }
+
+    /// Clamps each lane of `a`, `b`, `c`, and `d` to `0..=255` and packs them, in that order, into
+    /// the sixteen bytes: `a[0]` lands in byte 0 and `d[3]` in byte 15. This is `I32x4::to_rgba8`
+    /// for four vectors at once.
+    #[inline]
+    pub fn pack_i32x4s(a: I32x4, b: I32x4, c: I32x4, d: I32x4) -> U8x16 {
+        // As in `to_rgba8`, pack with signed saturation first, since `_mm_packus_epi16` reads its
+        // inputs as signed.
+        unsafe {
+            let (lo, hi) = (x86::_mm_packs_epi32(a.0, b.0), x86::_mm_packs_epi32(c.0, d.0));
+            U8x16(x86::_mm_packus_epi16(lo, hi))
+        }
+    }
+
+    // Basic operations
+
+    /// Returns a vector whose byte `i` is byte `indices[i] & 15` of this vector, or zero if the
+    /// high bit of `indices[i]` is set.
+    #[inline]
+    pub fn shuffle(self, indices: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_shuffle_epi8(self.0, indices.0)) }
+    }
+
+    /// Packs the high bit of each byte into the low sixteen bits of an integer, byte 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        unsafe { x86::_mm_movemask_epi8(self.0) as u32 }
+    }
+
+    /// Averages each pair of bytes, rounding halves up: `(a + b + 1) >> 1`.
+    #[inline]
+    pub fn avg(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_avg_epu8(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn min(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_min_epu8(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn max(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_max_epu8(self.0, other.0)) }
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_cmpeq_epi8(self.0, other.0)) }
+    }
+
+    // Filtering
+
+    /// Bilinearly interpolates, byte by byte, between the texels `tl`, `tr`, `bl`, and `br` at the
+    /// top left, top right, bottom left, and bottom right of a square. With RGBA8 data this
+    /// filters four pixels at once.
+    ///
+    /// The weights are fixed-point fractions with 8 fractional bits: 0 selects the left or top
+    /// texels, 256 selects the right or bottom ones, and 128 is halfway between. Weights above 256
+    /// are treated as 256. Each byte is interpolated horizontally and then vertically in 16-bit
+    /// lanes, rounding to nearest after each step.
+    #[inline]
+    pub fn bilinear_blend(tl: U8x16, tr: U8x16, bl: U8x16, br: U8x16, wx: u16, wy: u16)
+                          -> U8x16 {
+        let (wx, wy) = (wx.min(256), wy.min(256));
+        tl.lerp_fixed(tr, wx).lerp_fixed(bl.lerp_fixed(br, wx), wy)
+    }
+
+    // Computes `(self × (256 - weight) + other × weight + 128) >> 8` for each byte. `weight` must
+    // be at most 256.
+    #[inline]
+    fn lerp_fixed(self, other: U8x16, weight: u16) -> U8x16 {
+        unsafe {
+            let zero = x86::_mm_setzero_si128();
+            let self_weight = x86::_mm_set1_epi16((256 - weight) as i16);
+            let other_weight = x86::_mm_set1_epi16(weight as i16);
+            let round = x86::_mm_set1_epi16(128);
+            // Both products fit in 16 bits, as does their sum, since the weights add up to 256.
+            let lerp = |a, b| {
+                let sum = x86::_mm_add_epi16(x86::_mm_mullo_epi16(a, self_weight),
+                                             x86::_mm_mullo_epi16(b, other_weight));
+                x86::_mm_srli_epi16::<8>(x86::_mm_add_epi16(sum, round))
+            };
+            let lo = lerp(x86::_mm_unpacklo_epi8(self.0, zero),
+                          x86::_mm_unpacklo_epi8(other.0, zero));
+            let hi = lerp(x86::_mm_unpackhi_epi8(self.0, zero),
+                          x86::_mm_unpackhi_epi8(other.0, zero));
+            U8x16(x86::_mm_packus_epi16(lo, hi))
+        }
+    }
+}
+
+impl Debug for U8x16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let bytes = self.to_array();
+        write!(f, "<{}", bytes[0])?;
+        for byte in &bytes[1..] {
+            write!(f, ", {}", byte)?;
+        }
+        write!(f, ">")
+    }
+}
+
+impl Index<usize> for U8x16 {
+    type Output = u8;
+    #[inline]
+    fn index(&self, index: usize) -> &u8 {
+        unsafe { &mem::transmute::<&__m128i, &[u8; 16]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for U8x16 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        unsafe { &mut mem::transmute::<&mut __m128i, &mut [u8; 16]>(&mut self.0)[index] }
+    }
+}
+
+impl PartialEq for U8x16 {
+    #[inline]
+    fn eq(&self, other: &U8x16) -> bool {
+        self.packed_eq(*other).movemask() == 0xffff
+    }
+}
+
+// Byte arithmetic saturates instead of wrapping, since that is what pixel and coverage math wants.
+impl Add<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn add(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_adds_epu8(self.0, other.0)) }
+    }
+}
+
+impl Sub<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn sub(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_subs_epu8(self.0, other.0)) }
+    }
+}
+
+impl Not for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn not(self) -> U8x16 {
+        self ^ U8x16::splat(!0)
+    }
+}
+
+impl BitAnd<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitand(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_and_si128(self.0, other.0)) }
+    }
+}
+
+impl BitOr<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitor(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_or_si128(self.0, other.0)) }
+    }
+}
+
+impl BitXor<U8x16> for U8x16 {
+    type Output = U8x16;
+    #[inline]
+    fn bitxor(self, other: U8x16) -> U8x16 {
+        unsafe { U8x16(x86::_mm_xor_si128(self.0, other.0)) }
+    }
+}
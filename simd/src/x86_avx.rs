@@ -0,0 +1,426 @@
+// pathfinder/simd/src/x86_avx.rs
+//
+// Copyright © 2019 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Eight-lane vectors using 256-bit AVX registers.
+//!
+//! This module is only compiled when the `avx` feature is enabled and the target supports AVX,
+//! for example with `-C target-feature=+avx`. `I32x8` additionally requires AVX2.
+
+use std::cmp::PartialEq;
+use std::fmt::{self, Debug, Formatter};
+use std::mem;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Index, IndexMut, Mul, Not, Sub};
+
+#[cfg(target_pointer_width = "32")]
+use std::arch::x86::{__m256, __m256i};
+#[cfg(target_pointer_width = "32")]
+use std::arch::x86;
+#[cfg(target_pointer_width = "64")]
+use std::arch::x86_64::{__m256, __m256i};
+#[cfg(target_pointer_width = "64")]
+use std::arch::x86_64 as x86;
+
+// Eight 32-bit floats
+
+#[derive(Clone, Copy)]
+pub struct F32x8(pub __m256);
+
+impl F32x8 {
+    // Constructors
+
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32) -> F32x8 {
+        F32x8::from_array([a, b, c, d, e, f, g, h])
+    }
+
+    #[inline]
+    pub fn splat(x: f32) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_set1_ps(x)) }
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [f32; 8]) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_loadu_ps(array.as_ptr())) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 8] {
+        let mut array = [0.0; 8];
+        unsafe { x86::_mm256_storeu_ps(array.as_mut_ptr(), self.0) }
+        array
+    }
+
+    // Basic operations
+
+    #[inline]
+    pub fn min(self, other: F32x8) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_min_ps(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn max(self, other: F32x8) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_max_ps(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn abs(self) -> F32x8 {
+        // -0.0 has only the sign bit set, so this clears the sign bit and nothing else.
+        unsafe { F32x8(x86::_mm256_andnot_ps(x86::_mm256_set1_ps(-0.0), self.0)) }
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: F32x8) -> U32x8 {
+        unsafe {
+            let mask = x86::_mm256_cmp_ps::<{ x86::_CMP_EQ_OQ }>(self.0, other.0);
+            U32x8(x86::_mm256_castps_si256(mask))
+        }
+    }
+
+    #[inline]
+    pub fn packed_gt(self, other: F32x8) -> U32x8 {
+        unsafe {
+            let mask = x86::_mm256_cmp_ps::<{ x86::_CMP_GT_OQ }>(self.0, other.0);
+            U32x8(x86::_mm256_castps_si256(mask))
+        }
+    }
+
+    #[inline]
+    pub fn packed_lt(self, other: F32x8) -> U32x8 {
+        other.packed_gt(self)
+    }
+
+    #[inline]
+    pub fn packed_le(self, other: F32x8) -> U32x8 {
+        // "Not greater than" is true for NaN lanes, matching `F32x4::packed_le`.
+        unsafe {
+            let mask = x86::_mm256_cmp_ps::<{ x86::_CMP_NGT_UQ }>(self.0, other.0);
+            U32x8(x86::_mm256_castps_si256(mask))
+        }
+    }
+}
+
+impl Default for F32x8 {
+    #[inline]
+    fn default() -> F32x8 {
+        unsafe { F32x8(x86::_mm256_setzero_ps()) }
+    }
+}
+
+impl Index<usize> for F32x8 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, index: usize) -> &f32 {
+        unsafe { &mem::transmute::<&__m256, &[f32; 8]>(&self.0)[index] }
+    }
+}
+
+impl IndexMut<usize> for F32x8 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        unsafe { &mut mem::transmute::<&mut __m256, &mut [f32; 8]>(&mut self.0)[index] }
+    }
+}
+
+impl Debug for F32x8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}, {}, {}, {}, {}, {}, {}>",
+               self[0], self[1], self[2], self[3], self[4], self[5], self[6], self[7])
+    }
+}
+
+impl PartialEq for F32x8 {
+    #[inline]
+    fn eq(&self, other: &F32x8) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+impl Add<F32x8> for F32x8 {
+    type Output = F32x8;
+    #[inline]
+    fn add(self, other: F32x8) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_add_ps(self.0, other.0)) }
+    }
+}
+
+impl Div<F32x8> for F32x8 {
+    type Output = F32x8;
+    #[inline]
+    fn div(self, other: F32x8) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_div_ps(self.0, other.0)) }
+    }
+}
+
+impl Mul<F32x8> for F32x8 {
+    type Output = F32x8;
+    #[inline]
+    fn mul(self, other: F32x8) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_mul_ps(self.0, other.0)) }
+    }
+}
+
+impl Sub<F32x8> for F32x8 {
+    type Output = F32x8;
+    #[inline]
+    fn sub(self, other: F32x8) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_sub_ps(self.0, other.0)) }
+    }
+}
+
+// Eight 32-bit signed integers
+
+#[cfg(target_feature = "avx2")]
+#[derive(Clone, Copy)]
+pub struct I32x8(pub __m256i);
+
+#[cfg(target_feature = "avx2")]
+impl I32x8 {
+    // Constructors
+
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn new(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32) -> I32x8 {
+        I32x8::from_array([a, b, c, d, e, f, g, h])
+    }
+
+    #[inline]
+    pub fn splat(x: i32) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_set1_epi32(x)) }
+    }
+
+    // Loads and stores
+
+    #[inline]
+    pub fn from_array(array: [i32; 8]) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_loadu_si256(array.as_ptr() as *const __m256i)) }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 8] {
+        let mut array = [0; 8];
+        unsafe { x86::_mm256_storeu_si256(array.as_mut_ptr() as *mut __m256i, self.0) }
+        array
+    }
+
+    // Basic operations
+
+    #[inline]
+    pub fn min(self, other: I32x8) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_min_epi32(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn max(self, other: I32x8) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_max_epi32(self.0, other.0)) }
+    }
+
+    // Packed comparisons
+
+    #[inline]
+    pub fn packed_eq(self, other: I32x8) -> U32x8 {
+        unsafe { U32x8(x86::_mm256_cmpeq_epi32(self.0, other.0)) }
+    }
+
+    #[inline]
+    pub fn packed_gt(self, other: I32x8) -> U32x8 {
+        unsafe { U32x8(x86::_mm256_cmpgt_epi32(self.0, other.0)) }
+    }
+
+    // Conversions
+
+    /// Converts these packed integers to floats.
+    #[inline]
+    pub fn to_f32x8(self) -> F32x8 {
+        unsafe { F32x8(x86::_mm256_cvtepi32_ps(self.0)) }
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl Default for I32x8 {
+    #[inline]
+    fn default() -> I32x8 {
+        unsafe { I32x8(x86::_mm256_setzero_si256()) }
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl Index<usize> for I32x8 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &i32 {
+        unsafe { &mem::transmute::<&__m256i, &[i32; 8]>(&self.0)[index] }
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl IndexMut<usize> for I32x8 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut i32 {
+        unsafe { &mut mem::transmute::<&mut __m256i, &mut [i32; 8]>(&mut self.0)[index] }
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl Debug for I32x8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}, {}, {}, {}, {}, {}, {}>",
+               self[0], self[1], self[2], self[3], self[4], self[5], self[6], self[7])
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl PartialEq for I32x8 {
+    #[inline]
+    fn eq(&self, other: &I32x8) -> bool {
+        self.packed_eq(*other).all_true()
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl Add<I32x8> for I32x8 {
+    type Output = I32x8;
+    #[inline]
+    fn add(self, other: I32x8) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_add_epi32(self.0, other.0)) }
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl Sub<I32x8> for I32x8 {
+    type Output = I32x8;
+    #[inline]
+    fn sub(self, other: I32x8) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_sub_epi32(self.0, other.0)) }
+    }
+}
+
+#[cfg(target_feature = "avx2")]
+impl Mul<I32x8> for I32x8 {
+    type Output = I32x8;
+    #[inline]
+    fn mul(self, other: I32x8) -> I32x8 {
+        unsafe { I32x8(x86::_mm256_mullo_epi32(self.0, other.0)) }
+    }
+}
+
+// Eight 32-bit unsigned integers
+
+#[derive(Clone, Copy)]
+pub struct U32x8(pub __m256i);
+
+impl U32x8 {
+    // Loads and stores
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 8] {
+        let mut array = [0; 8];
+        unsafe { x86::_mm256_storeu_si256(array.as_mut_ptr() as *mut __m256i, self.0) }
+        array
+    }
+
+    // Basic operations
+
+    /// Returns true if all eight booleans in this vector are true.
+    ///
+    /// The result is *undefined* if all eight values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn all_true(self) -> bool {
+        self.movemask() == 0xff
+    }
+
+    /// Returns true if all eight booleans in this vector are false.
+    ///
+    /// The result is *undefined* if all eight values in this vector are not booleans. A boolean is
+    /// a value with all bits set or all bits clear (i.e. !0 or 0).
+    #[inline]
+    pub fn all_false(self) -> bool {
+        self.movemask() == 0
+    }
+
+    /// Packs the high bit of each lane into the low eight bits of an integer, lane 0 first.
+    #[inline]
+    pub fn movemask(self) -> u32 {
+        unsafe { x86::_mm256_movemask_ps(x86::_mm256_castsi256_ps(self.0)) as u32 }
+    }
+}
+
+impl Index<usize> for U32x8 {
+    type Output = u32;
+    #[inline]
+    fn index(&self, index: usize) -> &u32 {
+        unsafe { &mem::transmute::<&__m256i, &[u32; 8]>(&self.0)[index] }
+    }
+}
+
+impl Debug for U32x8 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(f, "<{}, {}, {}, {}, {}, {}, {}, {}>",
+               self[0], self[1], self[2], self[3], self[4], self[5], self[6], self[7])
+    }
+}
+
+impl PartialEq for U32x8 {
+    #[inline]
+    fn eq(&self, other: &U32x8) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+// Plain AVX has no 256-bit integer logic, so these go through the float domain, which is
+// bit-exact for logical operations.
+impl Not for U32x8 {
+    type Output = U32x8;
+    #[inline]
+    fn not(self) -> U32x8 {
+        unsafe { self ^ U32x8(x86::_mm256_set1_epi32(!0)) }
+    }
+}
+
+impl BitAnd<U32x8> for U32x8 {
+    type Output = U32x8;
+    #[inline]
+    fn bitand(self, other: U32x8) -> U32x8 {
+        unsafe {
+            let (a, b) = (x86::_mm256_castsi256_ps(self.0), x86::_mm256_castsi256_ps(other.0));
+            U32x8(x86::_mm256_castps_si256(x86::_mm256_and_ps(a, b)))
+        }
+    }
+}
+
+impl BitOr<U32x8> for U32x8 {
+    type Output = U32x8;
+    #[inline]
+    fn bitor(self, other: U32x8) -> U32x8 {
+        unsafe {
+            let (a, b) = (x86::_mm256_castsi256_ps(self.0), x86::_mm256_castsi256_ps(other.0));
+            U32x8(x86::_mm256_castps_si256(x86::_mm256_or_ps(a, b)))
+        }
+    }
+}
+
+impl BitXor<U32x8> for U32x8 {
+    type Output = U32x8;
+    #[inline]
+    fn bitxor(self, other: U32x8) -> U32x8 {
+        unsafe {
+            let (a, b) = (x86::_mm256_castsi256_ps(self.0), x86::_mm256_castsi256_ps(other.0));
+            U32x8(x86::_mm256_castps_si256(x86::_mm256_xor_ps(a, b)))
+        }
+    }
+}